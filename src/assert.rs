@@ -0,0 +1,184 @@
+//! Assertion macros for the state of [SoftResult]s and [MalleableResult]s, meant for tests.
+
+use core::fmt::Debug;
+
+use crate::{MalleableResult, SoftResult};
+
+/// Extracts the ok value or the soft error of a [SoftResult] or a [MalleableResult], giving the value back otherwise.
+/// Used by [assert_soft_ok](crate::assert_soft_ok) and [assert_soft_err](crate::assert_soft_err).
+#[doc(hidden)]
+pub trait AssertSoft: Debug + Sized {
+    type Ok;
+    type Soft;
+
+    fn into_ok(self) -> Result<Self::Ok, Self>;
+
+    fn into_soft_err(self) -> Result<Self::Soft, Self>;
+}
+
+impl<T: Debug, E: Debug> AssertSoft for SoftResult<T, E> {
+    type Ok = T;
+    type Soft = E;
+
+    fn into_ok(self) -> Result<T, Self> {
+        match self {
+            SoftResult::Ok(t) => Ok(t),
+            soft_result => Err(soft_result),
+        }
+    }
+
+    fn into_soft_err(self) -> Result<E, Self> {
+        match self {
+            SoftResult::SoftErr(e) => Ok(e),
+            soft_result => Err(soft_result),
+        }
+    }
+}
+
+impl<T: Debug, S: Debug, H: Debug> AssertSoft for MalleableResult<T, S, H> {
+    type Ok = T;
+    type Soft = S;
+
+    fn into_ok(self) -> Result<T, Self> {
+        match self {
+            Ok(SoftResult::Ok(t)) => Ok(t),
+            result => Err(result),
+        }
+    }
+
+    fn into_soft_err(self) -> Result<S, Self> {
+        match self {
+            Ok(SoftResult::SoftErr(s)) => Ok(s),
+            result => Err(result),
+        }
+    }
+}
+
+#[macro_export]
+/// Asserts that a [SoftResult] or a [MalleableResult] holds an ok value, and evaluates to it.
+/// Otherwise panics with the [Debug] of the actual value, followed by the optional custom message.
+///
+/// ```rust
+/// use try_hard::{assert_soft_ok, MalleableResult, SoftResult};
+///
+/// let result: MalleableResult<Vec<i32>, String, String> = Ok(SoftResult::Ok(vec![1, 2]));
+/// let items = assert_soft_ok!(result);
+/// assert_eq!(items.len(), 2);
+/// ```
+macro_rules! assert_soft_ok {
+    ($e:expr $(,)?) => {
+        match $crate::__private::AssertSoft::into_ok($e) {
+            ::core::result::Result::Ok(t) => t,
+            ::core::result::Result::Err(actual) => {
+                ::core::panic!("assertion failed: expected an ok value, got: {:?}", actual)
+            }
+        }
+    };
+    ($e:expr, $($arg:tt)+) => {
+        match $crate::__private::AssertSoft::into_ok($e) {
+            ::core::result::Result::Ok(t) => t,
+            ::core::result::Result::Err(actual) => ::core::panic!(
+                "assertion failed: expected an ok value, got: {:?}: {}",
+                actual,
+                ::core::format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+#[macro_export]
+/// Asserts that a [SoftResult] or a [MalleableResult] holds a soft error, and evaluates to it.
+/// Otherwise panics with the [Debug] of the actual value, followed by the optional custom message.
+macro_rules! assert_soft_err {
+    ($e:expr $(,)?) => {
+        match $crate::__private::AssertSoft::into_soft_err($e) {
+            ::core::result::Result::Ok(e) => e,
+            ::core::result::Result::Err(actual) => {
+                ::core::panic!("assertion failed: expected a soft error, got: {:?}", actual)
+            }
+        }
+    };
+    ($e:expr, $($arg:tt)+) => {
+        match $crate::__private::AssertSoft::into_soft_err($e) {
+            ::core::result::Result::Ok(e) => e,
+            ::core::result::Result::Err(actual) => ::core::panic!(
+                "assertion failed: expected a soft error, got: {:?}: {}",
+                actual,
+                ::core::format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+#[macro_export]
+/// Asserts that a [MalleableResult] holds a hard error, and evaluates to it.
+/// Otherwise panics with the [Debug] of the actual value, followed by the optional custom message.
+macro_rules! assert_hard_err {
+    ($e:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Err(e) => e,
+            actual => ::core::panic!("assertion failed: expected a hard error, got: {:?}", actual),
+        }
+    };
+    ($e:expr, $($arg:tt)+) => {
+        match $e {
+            ::core::result::Result::Err(e) => e,
+            actual => ::core::panic!(
+                "assertion failed: expected a hard error, got: {:?}: {}",
+                actual,
+                ::core::format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct HardError;
+
+    #[test]
+    fn check_assertions_bind_values() {
+        assert_eq!(assert_soft_ok!(SoftResult::<i32, SoftError>::Ok(1)), 1);
+        assert_eq!(
+            assert_soft_err!(SoftResult::<i32, SoftError>::SoftErr(SoftError)),
+            SoftError
+        );
+        let ok: MalleableResult<i32, SoftError, HardError> = Ok(SoftResult::Ok(1));
+        assert_eq!(assert_soft_ok!(ok), 1);
+        let soft: MalleableResult<i32, SoftError, HardError> = Ok(SoftResult::SoftErr(SoftError));
+        assert_eq!(
+            assert_soft_err!(soft, "while checking {}", "soft"),
+            SoftError
+        );
+        let hard: MalleableResult<i32, SoftError, HardError> = Err(HardError);
+        assert_eq!(assert_hard_err!(hard), HardError);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected an ok value, got: SoftErr(SoftError)")]
+    fn check_assert_soft_ok_panics() {
+        assert_soft_ok!(SoftResult::<i32, SoftError>::SoftErr(SoftError));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: expected a soft error, got: Err(HardError): user 7"
+    )]
+    fn check_assert_soft_err_panics() {
+        let hard: MalleableResult<i32, SoftError, HardError> = Err(HardError);
+        assert_soft_err!(hard, "user {}", 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected a hard error, got: Ok(Ok(1))")]
+    fn check_assert_hard_err_panics() {
+        let ok: MalleableResult<i32, SoftError, HardError> = Ok(SoftResult::Ok(1));
+        assert_hard_err!(ok);
+    }
+}
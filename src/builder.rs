@@ -0,0 +1,140 @@
+//! A builder validating a value with several checks.
+//! Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+
+use crate::SoftResult;
+
+/// Validates a value with several checks, then builds a [SoftResult] with the value or the soft errors.
+///
+/// By default, every check runs and all of their soft errors are collected.
+/// In short-circuit mode, the checks after the first soft error are skipped.
+///
+/// ```rust
+/// use try_hard::{SoftResult, SoftResultBuilder};
+///
+/// fn non_empty(name: &&str) -> SoftResult<(), &'static str> {
+///     if name.is_empty() {
+///         return SoftResult::SoftErr("empty");
+///     }
+///     SoftResult::Ok(())
+/// }
+///
+/// fn capitalized(name: &&str) -> SoftResult<(), &'static str> {
+///     if !name.starts_with(char::is_uppercase) {
+///         return SoftResult::SoftErr("not capitalized");
+///     }
+///     SoftResult::Ok(())
+/// }
+///
+/// let validated = SoftResultBuilder::new("")
+///     .check(non_empty)
+///     .check(capitalized)
+///     .build();
+/// assert_eq!(validated, SoftResult::SoftErr(vec!["empty", "not capitalized"]));
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "the builder does nothing unless built"]
+pub struct SoftResultBuilder<T, E> {
+    value: T,
+    soft_errs: Vec<E>,
+    accumulate: bool,
+}
+
+impl<T, E> SoftResultBuilder<T, E> {
+    /// Starts validating `value`, in accumulate mode.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        SoftResultBuilder {
+            value,
+            soft_errs: Vec::new(),
+            accumulate: true,
+        }
+    }
+
+    /// Selects whether the following checks run after a soft error (`true`, the default), or are skipped (`false`).
+    #[inline]
+    pub fn accumulate(mut self, accumulate: bool) -> Self {
+        self.accumulate = accumulate;
+        self
+    }
+
+    /// Runs `check` on the value, keeping its soft error, if any.
+    /// In short-circuit mode, `check` is not called if a previous check already failed.
+    pub fn check<F: FnOnce(&T) -> SoftResult<(), E>>(mut self, check: F) -> Self {
+        if !self.accumulate && !self.soft_errs.is_empty() {
+            return self;
+        }
+        if let SoftResult::SoftErr(e) = check(&self.value) {
+            self.soft_errs.push(e);
+        }
+        self
+    }
+
+    /// Returns the value if every check passed, or the soft errors of the checks that failed, in order.
+    pub fn build(self) -> SoftResult<T, Vec<E>> {
+        if self.soft_errs.is_empty() {
+            SoftResult::Ok(self.value)
+        } else {
+            SoftResult::SoftErr(self.soft_errs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn at_least(min: i32) -> impl FnOnce(&i32) -> SoftResult<(), String> {
+        move |x| {
+            if *x < min {
+                return SoftResult::SoftErr(format!("less than {min}"));
+            }
+            SoftResult::Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case(true, 10, SoftResult::Ok(10))]
+    #[case(true, 0, SoftResult::SoftErr(vec!["less than 1".to_string(), "less than 5".to_string()]))]
+    #[case(true, 3, SoftResult::SoftErr(vec!["less than 5".to_string()]))]
+    #[case(false, 10, SoftResult::Ok(10))]
+    #[case(false, 0, SoftResult::SoftErr(vec!["less than 1".to_string()]))]
+    #[case(false, 3, SoftResult::SoftErr(vec!["less than 5".to_string()]))]
+    fn check_builder(
+        #[case] accumulate: bool,
+        #[case] value: i32,
+        #[case] expected: SoftResult<i32, Vec<String>>,
+    ) {
+        let validated = SoftResultBuilder::new(value)
+            .accumulate(accumulate)
+            .check(at_least(1))
+            .check(at_least(5))
+            .build();
+        assert_eq!(validated, expected);
+    }
+
+    #[rstest]
+    #[case(true, 2)]
+    #[case(false, 1)]
+    fn check_builder_skips_checks_in_short_circuit_mode(
+        #[case] accumulate: bool,
+        #[case] expected_calls: usize,
+    ) {
+        let calls = Cell::new(0);
+        let counted = |x: &i32| {
+            calls.set(calls.get() + 1);
+            at_least(1)(x)
+        };
+        let _ = SoftResultBuilder::new(0)
+            .accumulate(accumulate)
+            .check(counted)
+            .check(counted)
+            .build();
+        assert_eq!(calls.get(), expected_calls);
+    }
+}
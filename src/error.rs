@@ -0,0 +1,176 @@
+//! Error types that wrap soft and hard errors, so they can be reported through the [Error] trait.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// A soft error that was escalated into a hard error, by [try_escalate](crate::try_escalate) or [SoftResult::escalate](crate::SoftResult::escalate).
+///
+/// Its [Display](fmt::Display) is `escalated soft error: {soft error}`, and its [Error::source] is the soft error.
+///
+/// ```rust
+/// use try_hard::{Escalated, SoftResult};
+///
+/// let result = SoftResult::<(), _>::SoftErr(std::fmt::Error).escalate();
+/// assert_eq!(result, Err(Escalated(std::fmt::Error)));
+/// assert_eq!(
+///     result.unwrap_err().to_string(),
+///     "escalated soft error: an error occurred when formatting an argument"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Escalated<E>(pub E);
+
+impl<E: fmt::Display> fmt::Display for Escalated<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "escalated soft error: {}", self.0)
+    }
+}
+
+impl<E: Error + 'static> Error for Escalated<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Either error of a [MalleableResult](crate::MalleableResult), flattened into a single error type
+/// by [MalleableResultExt::into_hard_result](crate::MalleableResultExt::into_hard_result).
+///
+/// Its [Display](fmt::Display) is `hard error: {hard error}` or `soft error: {soft error}`, and its [Error::source] is the wrapped error.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HardOrSoft<H, S> {
+    /// A hard error.
+    Hard(H),
+    /// A soft error.
+    Soft(S),
+}
+
+impl<H: fmt::Display, S: fmt::Display> fmt::Display for HardOrSoft<H, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardOrSoft::Hard(h) => write!(f, "hard error: {h}"),
+            HardOrSoft::Soft(s) => write!(f, "soft error: {s}"),
+        }
+    }
+}
+
+impl<H: Error + 'static, S: Error + 'static> Error for HardOrSoft<H, S> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HardOrSoft::Hard(h) => Some(h),
+            HardOrSoft::Soft(s) => Some(s),
+        }
+    }
+}
+
+/// Every soft error of an accumulate-all validation. Requires the `alloc` feature.
+///
+/// Its [Display](fmt::Display) joins the soft errors with `; `.
+/// It can be collected from soft errors, or built from the result of [collect_soft](crate::collect_soft):
+///
+/// ```rust
+/// use try_hard::{collect_soft, SoftErrors, SoftResult};
+///
+/// let results = vec![SoftResult::Ok(1), SoftResult::SoftErr("empty name"), SoftResult::SoftErr("invalid email")];
+/// let collected = collect_soft(results).map_soft_err(SoftErrors);
+/// assert_eq!(collected.unwrap_soft_err().to_string(), "empty name; invalid email");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SoftErrors<E>(pub Vec<E>);
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display> fmt::Display for SoftErrors<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{e}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Debug + fmt::Display> Error for SoftErrors<E> {}
+
+#[cfg(feature = "alloc")]
+impl<E> FromIterator<E> for SoftErrors<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        SoftErrors(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::SoftResult;
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+    #[error("a soft error")]
+    struct SoftError;
+
+    #[test]
+    fn check_escalated_display() {
+        assert_eq!(
+            Escalated(SoftError).to_string(),
+            "escalated soft error: a soft error"
+        );
+    }
+
+    #[test]
+    fn check_escalated_source() {
+        let escalated = Escalated(SoftError);
+        let source = escalated.source().unwrap();
+        assert_eq!(source.to_string(), "a soft error");
+        assert_eq!(source.downcast_ref::<SoftError>(), Some(&SoftError));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(vec![], "")]
+    #[case(vec!["empty name"], "empty name")]
+    #[case(vec!["empty name", "invalid email"], "empty name; invalid email")]
+    fn check_soft_errors_display(#[case] soft_errs: Vec<&str>, #[case] expected: &str) {
+        let soft_errors: SoftErrors<&str> = soft_errs.into_iter().collect();
+        assert_eq!(soft_errors.to_string(), expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(vec![SoftResult::Ok(1), SoftResult::Ok(2)], SoftResult::Ok(vec![1, 2]))]
+    #[case(
+        vec![SoftResult::SoftErr("empty name"), SoftResult::Ok(2), SoftResult::SoftErr("invalid email")],
+        SoftResult::SoftErr(SoftErrors(vec!["empty name", "invalid email"]))
+    )]
+    fn check_soft_errors_collect_soft(
+        #[case] results: Vec<SoftResult<i32, &str>>,
+        #[case] expected: SoftResult<Vec<i32>, SoftErrors<&str>>,
+    ) {
+        assert_eq!(
+            crate::collect_soft(results).map_soft_err(SoftErrors),
+            expected
+        );
+    }
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+    #[error("a real dangerous error")]
+    struct HardError;
+
+    #[rstest]
+    #[case(HardOrSoft::Hard(HardError), "hard error: a real dangerous error")]
+    #[case(HardOrSoft::Soft(SoftError), "soft error: a soft error")]
+    fn check_hard_or_soft_display_and_source(
+        #[case] error: HardOrSoft<HardError, SoftError>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(error.to_string(), expected);
+        let source = error.source().unwrap().to_string();
+        assert!(expected.ends_with(&source));
+    }
+}
@@ -0,0 +1,529 @@
+//! Extension traits, adding [SoftResult] related methods to foreign types.
+
+use core::fmt::Debug;
+
+use crate::{HardOrSoft, MalleableResult, SoftResult};
+
+/// Extends the core [Result] with conversions into a [SoftResult].
+pub trait ResultExt<T, E> {
+    /// Treats the [Err] of this result as a soft error, converting it into a [SoftResult].
+    ///
+    /// ```rust
+    /// use try_hard::{ResultExt, SoftResult};
+    ///
+    /// let parsed = "not a number".parse::<i32>().soften();
+    /// assert!(parsed.is_soft_err());
+    /// ```
+    fn soften(self) -> SoftResult<T, E>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline]
+    fn soften(self) -> SoftResult<T, E> {
+        SoftResult::from(self)
+    }
+}
+
+/// Extends [MalleableResult] with helpers that operate on the right nesting level,
+/// without having to match on both the [Result] and the [SoftResult].
+pub trait MalleableResultExt<T, S, H> {
+    /// Maps the ok value, leaving soft and hard errors untouched.
+    fn map_ok<U, F: FnOnce(T) -> U>(self, f: F) -> MalleableResult<U, S, H>;
+
+    /// Maps the soft error, leaving ok values and hard errors untouched.
+    fn map_soft<S2, F: FnOnce(S) -> S2>(self, f: F) -> MalleableResult<T, S2, H>;
+
+    /// Maps the hard error, leaving ok values and soft errors untouched.
+    fn map_hard<H2, F: FnOnce(H) -> H2>(self, f: F) -> MalleableResult<T, S, H2>;
+
+    /// Returns `true` if the result is an ok value, `Ok(SoftResult::Ok(_))`.
+    ///
+    /// This is not called `is_ok`, as it would be shadowed by [Result::is_ok],
+    /// which is also `true` for soft errors.
+    fn is_success(&self) -> bool;
+
+    /// Returns `true` if the result is a soft error, `Ok(SoftResult::SoftErr(_))`.
+    fn is_soft_err(&self) -> bool;
+
+    /// Returns `true` if the result is a hard error, `Err(_)`.
+    fn is_hard_err(&self) -> bool;
+
+    /// Splits the result into one [Option] per state, where exactly one of them is [Some].
+    fn split(self) -> (Option<T>, Option<S>, Option<H>);
+
+    /// Flattens both error channels into a single error type, discarding the distinction between soft and hard errors.
+    /// Useful at the application boundary, where only success or failure matters.
+    fn into_result_with<E, FS, FH>(self, soft_into: FS, hard_into: FH) -> Result<T, E>
+    where
+        FS: FnOnce(S) -> E,
+        FH: FnOnce(H) -> E;
+
+    /// Escalates a soft error into a hard error, if `predicate` returns one for it.
+    /// Ok values, soft errors for which `predicate` returns [None], and hard errors are returned unchanged.
+    fn escalate_if<F: FnOnce(&S) -> Option<H>>(self, predicate: F) -> MalleableResult<T, S, H>;
+
+    /// Flattens the nesting into a single [Result], keeping the distinction between the errors in [HardOrSoft].
+    fn into_hard_result(self) -> Result<T, HardOrSoft<H, S>>;
+
+    /// Escalates a soft error into the hard error built by `make`, if it matches `pred`.
+    /// Unlike [MalleableResultExt::escalate_if], the soft error is moved into `make`.
+    fn soft_to_hard_on<P, M>(self, pred: P, make: M) -> MalleableResult<T, S, H>
+    where
+        P: FnOnce(&S) -> bool,
+        M: FnOnce(S) -> H;
+
+    /// Maps both error types at once, applying `soft` to a soft error or `hard` to a hard error.
+    /// Ok values are returned unchanged, and only the closure matching the state runs.
+    fn map_errors<S2, H2, FS, FH>(self, soft: FS, hard: FH) -> MalleableResult<T, S2, H2>
+    where
+        FS: FnOnce(S) -> S2,
+        FH: FnOnce(H) -> H2;
+
+    /// Returns the ok value, panicking with the actual state otherwise. Useful in tests.
+    #[track_caller]
+    fn unwrap_ok(self) -> T
+    where
+        S: Debug,
+        H: Debug;
+
+    /// Returns the soft error, panicking with the actual state otherwise. Useful in tests.
+    #[track_caller]
+    fn unwrap_soft(self) -> S
+    where
+        T: Debug,
+        H: Debug;
+
+    /// Returns the hard error, panicking with the actual state otherwise. Useful in tests.
+    #[track_caller]
+    fn unwrap_hard(self) -> H
+    where
+        T: Debug,
+        S: Debug;
+}
+
+impl<T, S, H> MalleableResultExt<T, S, H> for MalleableResult<T, S, H> {
+    #[inline]
+    fn map_ok<U, F: FnOnce(T) -> U>(self, f: F) -> MalleableResult<U, S, H> {
+        self.map(|soft_result| soft_result.map(f))
+    }
+
+    #[inline]
+    fn map_soft<S2, F: FnOnce(S) -> S2>(self, f: F) -> MalleableResult<T, S2, H> {
+        self.map(|soft_result| soft_result.map_soft_err(f))
+    }
+
+    #[inline]
+    fn map_hard<H2, F: FnOnce(H) -> H2>(self, f: F) -> MalleableResult<T, S, H2> {
+        self.map_err(f)
+    }
+
+    #[inline]
+    fn is_success(&self) -> bool {
+        matches!(self, Ok(SoftResult::Ok(_)))
+    }
+
+    #[inline]
+    fn is_soft_err(&self) -> bool {
+        matches!(self, Ok(SoftResult::SoftErr(_)))
+    }
+
+    #[inline]
+    fn is_hard_err(&self) -> bool {
+        self.is_err()
+    }
+
+    #[inline]
+    fn split(self) -> (Option<T>, Option<S>, Option<H>) {
+        match self {
+            Ok(SoftResult::Ok(t)) => (Some(t), None, None),
+            Ok(SoftResult::SoftErr(s)) => (None, Some(s), None),
+            Err(h) => (None, None, Some(h)),
+        }
+    }
+
+    #[inline]
+    fn into_result_with<E, FS, FH>(self, soft_into: FS, hard_into: FH) -> Result<T, E>
+    where
+        FS: FnOnce(S) -> E,
+        FH: FnOnce(H) -> E,
+    {
+        match self {
+            Ok(SoftResult::Ok(t)) => Ok(t),
+            Ok(SoftResult::SoftErr(s)) => Err(soft_into(s)),
+            Err(h) => Err(hard_into(h)),
+        }
+    }
+
+    #[inline]
+    fn escalate_if<F: FnOnce(&S) -> Option<H>>(self, predicate: F) -> MalleableResult<T, S, H> {
+        match self {
+            Ok(SoftResult::SoftErr(s)) => match predicate(&s) {
+                Some(h) => Err(h),
+                None => Ok(SoftResult::SoftErr(s)),
+            },
+            result => result,
+        }
+    }
+
+    #[inline]
+    fn into_hard_result(self) -> Result<T, HardOrSoft<H, S>> {
+        self.into_result_with(HardOrSoft::Soft, HardOrSoft::Hard)
+    }
+
+    #[inline]
+    fn soft_to_hard_on<P, M>(self, pred: P, make: M) -> MalleableResult<T, S, H>
+    where
+        P: FnOnce(&S) -> bool,
+        M: FnOnce(S) -> H,
+    {
+        match self {
+            Ok(SoftResult::SoftErr(s)) if pred(&s) => Err(make(s)),
+            result => result,
+        }
+    }
+
+    #[inline]
+    fn map_errors<S2, H2, FS, FH>(self, soft: FS, hard: FH) -> MalleableResult<T, S2, H2>
+    where
+        FS: FnOnce(S) -> S2,
+        FH: FnOnce(H) -> H2,
+    {
+        match self {
+            Ok(soft_result) => Ok(soft_result.map_soft_err(soft)),
+            Err(h) => Err(hard(h)),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_ok(self) -> T
+    where
+        S: Debug,
+        H: Debug,
+    {
+        match self {
+            Ok(SoftResult::Ok(t)) => t,
+            Ok(SoftResult::SoftErr(s)) => panic!("called unwrap_ok on a soft error: {s:?}"),
+            Err(h) => panic!("called unwrap_ok on a hard error: {h:?}"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_soft(self) -> S
+    where
+        T: Debug,
+        H: Debug,
+    {
+        match self {
+            Ok(SoftResult::Ok(t)) => panic!("called unwrap_soft on an ok value: {t:?}"),
+            Ok(SoftResult::SoftErr(s)) => s,
+            Err(h) => panic!("called unwrap_soft on a hard error: {h:?}"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_hard(self) -> H
+    where
+        T: Debug,
+        S: Debug,
+    {
+        match self {
+            Ok(SoftResult::Ok(t)) => panic!("called unwrap_hard on an ok value: {t:?}"),
+            Ok(SoftResult::SoftErr(s)) => panic!("called unwrap_hard on a soft error: {s:?}"),
+            Err(h) => h,
+        }
+    }
+}
+
+/// Extends [Option] with conversions into a [SoftResult], where [None] is a benign soft error, such as "not found".
+pub trait OptionExt<T> {
+    /// Converts the option into a [SoftResult], mapping [None] to the soft error `err`.
+    ///
+    /// ```rust
+    /// use try_hard::{OptionExt, SoftResult};
+    ///
+    /// let user: Option<&str> = None;
+    /// assert_eq!(user.ok_or_soft("not found"), SoftResult::SoftErr("not found"));
+    /// ```
+    fn ok_or_soft<E>(self, err: E) -> SoftResult<T, E>;
+
+    /// Converts the option into a [SoftResult], mapping [None] to the soft error returned by `f`.
+    /// `f` is not called on [Some].
+    fn ok_or_soft_else<E, F: FnOnce() -> E>(self, f: F) -> SoftResult<T, E>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[inline]
+    fn ok_or_soft<E>(self, err: E) -> SoftResult<T, E> {
+        SoftResult::from_option(self, err)
+    }
+
+    #[inline]
+    fn ok_or_soft_else<E, F: FnOnce() -> E>(self, f: F) -> SoftResult<T, E> {
+        SoftResult::from_option_with(self, f)
+    }
+}
+
+/// Extends nested [MalleableResult]s, as produced by higher-order combinators, with a way to collapse them.
+pub trait FlattenMalleable<T, S, H> {
+    /// Collapses one level of nesting, keeping the outermost error.
+    /// An outer hard error takes precedence over everything, then an outer soft error, then the inner result.
+    fn flatten_malleable(self) -> MalleableResult<T, S, H>;
+}
+
+impl<T, S, H> FlattenMalleable<T, S, H> for MalleableResult<MalleableResult<T, S, H>, S, H> {
+    #[inline]
+    fn flatten_malleable(self) -> MalleableResult<T, S, H> {
+        match self {
+            Ok(SoftResult::Ok(inner)) => inner,
+            Ok(SoftResult::SoftErr(s)) => Ok(SoftResult::SoftErr(s)),
+            Err(h) => Err(h),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::make;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct HardError;
+
+    #[rstest]
+    #[case(Ok(1), SoftResult::Ok(1))]
+    #[case(Err(SoftError), SoftResult::SoftErr(SoftError))]
+    fn check_soften(
+        #[case] result: Result<i32, SoftError>,
+        #[case] expected: SoftResult<i32, SoftError>,
+    ) {
+        assert_eq!(result.soften(), expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(2)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(Err(HardError), Err(HardError))]
+    fn check_map_ok(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+    ) {
+        assert_eq!(result.map_ok(|x| x + 1), expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::SoftErr("soft")))]
+    #[case(Err(HardError), Err(HardError))]
+    fn check_map_soft(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, &str, HardError>,
+    ) {
+        assert_eq!(result.map_soft(|_| "soft"), expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(Err(HardError), Err("hard"))]
+    fn check_map_hard(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, &str>,
+    ) {
+        assert_eq!(result.map_hard(|_| "hard"), expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), true, false, false)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), false, true, false)]
+    #[case(Err(HardError), false, false, true)]
+    fn check_malleable_predicates(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] is_success: bool,
+        #[case] is_soft_err: bool,
+        #[case] is_hard_err: bool,
+    ) {
+        assert_eq!(result.is_success(), is_success);
+        assert_eq!(result.is_soft_err(), is_soft_err);
+        assert_eq!(result.is_hard_err(), is_hard_err);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), (Some(1), None, None))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), (None, Some(SoftError), None))]
+    #[case(Err(HardError), (None, None, Some(HardError)))]
+    fn check_split(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: (Option<i32>, Option<SoftError>, Option<HardError>),
+    ) {
+        assert_eq!(result.split(), expected);
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum AppError {
+        Soft(SoftError),
+        Hard(HardError),
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(1))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Err(AppError::Soft(SoftError)))]
+    #[case(Err(HardError), Err(AppError::Hard(HardError)))]
+    fn check_into_result_with(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: Result<i32, AppError>,
+    ) {
+        assert_eq!(
+            result.into_result_with(AppError::Soft, AppError::Hard),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(Ok(SoftResult::Ok(1)))), Ok(SoftResult::Ok(1)))]
+    #[case(
+        Ok(SoftResult::Ok(Ok(SoftResult::SoftErr("inner")))),
+        Ok(SoftResult::SoftErr("inner"))
+    )]
+    #[case(Ok(SoftResult::Ok(Err("inner"))), Err("inner"))]
+    #[case(Ok(SoftResult::SoftErr("outer")), Ok(SoftResult::SoftErr("outer")))]
+    #[case(Err("outer"), Err("outer"))]
+    fn check_flatten_malleable(
+        #[case] nested: MalleableResult<MalleableResult<i32, &str, &str>, &str, &str>,
+        #[case] expected: MalleableResult<i32, &str, &str>,
+    ) {
+        assert_eq!(nested.flatten_malleable(), expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(2)), Ok(SoftResult::SoftErr(2)))]
+    #[case(Ok(SoftResult::SoftErr(3)), Err("too many requests"))]
+    #[case(Err("hard"), Err("hard"))]
+    fn check_escalate_if(
+        #[case] result: MalleableResult<i32, u32, &str>,
+        #[case] expected: MalleableResult<i32, u32, &str>,
+    ) {
+        let escalated =
+            result.escalate_if(|retries| (*retries >= 3).then_some("too many requests"));
+        assert_eq!(escalated, expected);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(1))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Err(HardOrSoft::Soft(SoftError)))]
+    #[case(Err(HardError), Err(HardOrSoft::Hard(HardError)))]
+    fn check_into_hard_result(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: Result<i32, HardOrSoft<HardError, SoftError>>,
+    ) {
+        assert_eq!(result.into_hard_result(), expected);
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    enum RequestError {
+        NotFound,
+        RateLimited(u32),
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(
+        Ok(SoftResult::SoftErr(RequestError::NotFound)),
+        Ok(SoftResult::SoftErr(RequestError::NotFound))
+    )]
+    #[case(Ok(SoftResult::SoftErr(RequestError::RateLimited(3))), Err("escalated RateLimited(3)".to_string()))]
+    #[case(Err("hard".to_string()), Err("hard".to_string()))]
+    fn check_soft_to_hard_on(
+        #[case] result: MalleableResult<i32, RequestError, String>,
+        #[case] expected: MalleableResult<i32, RequestError, String>,
+    ) {
+        let escalated = result.soft_to_hard_on(
+            |s| matches!(s, RequestError::RateLimited(_)),
+            |s| format!("escalated {s:?}"),
+        );
+        assert_eq!(escalated, expected);
+    }
+
+    #[rstest]
+    #[case(Some(1), SoftResult::Ok(1))]
+    #[case(None, SoftResult::SoftErr(SoftError))]
+    fn check_ok_or_soft(#[case] option: Option<i32>, #[case] expected: SoftResult<i32, SoftError>) {
+        assert_eq!(option.ok_or_soft(SoftError), expected);
+        assert_eq!(option.ok_or_soft_else(|| SoftError), expected);
+    }
+
+    #[test]
+    fn check_ok_or_soft_else_is_lazy() {
+        let soft_result: SoftResult<i32, SoftError> =
+            Some(1).ok_or_soft_else(|| panic!("should not be called"));
+        assert_eq!(soft_result, SoftResult::Ok(1));
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::SoftErr("soft")))]
+    #[case(Err(HardError), Err(500))]
+    fn check_map_errors(
+        #[case] result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, &str, u16>,
+    ) {
+        assert_eq!(result.map_errors(|_| "soft", |_| 500), expected);
+    }
+
+    #[test]
+    fn check_unwrap_malleable() {
+        assert_eq!(make::ok::<i32, SoftError, HardError>(1).unwrap_ok(), 1);
+        assert_eq!(
+            make::soft::<i32, SoftError, HardError>(SoftError).unwrap_soft(),
+            SoftError
+        );
+        assert_eq!(
+            make::hard::<i32, SoftError, HardError>(HardError).unwrap_hard(),
+            HardError
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_ok on a soft error: SoftError")]
+    fn check_unwrap_ok_on_soft_err() {
+        make::soft::<i32, SoftError, HardError>(SoftError).unwrap_ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_ok on a hard error: HardError")]
+    fn check_unwrap_ok_on_hard_err() {
+        make::hard::<i32, SoftError, HardError>(HardError).unwrap_ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_soft on an ok value: 1")]
+    fn check_unwrap_soft_on_ok() {
+        make::ok::<i32, SoftError, HardError>(1).unwrap_soft();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_soft on a hard error: HardError")]
+    fn check_unwrap_soft_on_hard_err() {
+        make::hard::<i32, SoftError, HardError>(HardError).unwrap_soft();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_hard on an ok value: 1")]
+    fn check_unwrap_hard_on_ok() {
+        make::ok::<i32, SoftError, HardError>(1).unwrap_hard();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_hard on a soft error: SoftError")]
+    fn check_unwrap_hard_on_soft_err() {
+        make::soft::<i32, SoftError, HardError>(SoftError).unwrap_hard();
+    }
+}
@@ -0,0 +1,275 @@
+//! Async combinators for futures resolving to a [MalleableResult].
+//! Requires the `futures` feature.
+
+use core::future::Future;
+
+use crate::{MalleableResult, SoftResult};
+
+/// Extends futures resolving to a [MalleableResult] with async combinators.
+/// Hard errors always short-circuit, so the closures only run on the relevant state.
+pub trait MalleableFutureExt<T, S, H>: Future<Output = MalleableResult<T, S, H>> {
+    /// Awaits this future, then awaits `f` on the ok value.
+    /// Soft and hard errors are returned without calling `f`.
+    fn and_then_ok<U, F, Fut>(self, f: F) -> impl Future<Output = MalleableResult<U, S, H>>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = MalleableResult<U, S, H>>;
+
+    /// Awaits this future, then awaits `f` on the soft error, allowing to recover from it.
+    /// Ok values and hard errors are returned without calling `f`.
+    fn or_else_soft<S2, F, Fut>(self, f: F) -> impl Future<Output = MalleableResult<T, S2, H>>
+    where
+        F: FnOnce(S) -> Fut,
+        Fut: Future<Output = MalleableResult<T, S2, H>>;
+}
+
+impl<T, S, H, Fut0> MalleableFutureExt<T, S, H> for Fut0
+where
+    Fut0: Future<Output = MalleableResult<T, S, H>>,
+{
+    async fn and_then_ok<U, F, Fut>(self, f: F) -> MalleableResult<U, S, H>
+    where
+        F: FnOnce(T) -> Fut,
+        Fut: Future<Output = MalleableResult<U, S, H>>,
+    {
+        match self.await {
+            Ok(SoftResult::Ok(t)) => f(t).await,
+            Ok(SoftResult::SoftErr(s)) => Ok(SoftResult::SoftErr(s)),
+            Err(h) => Err(h),
+        }
+    }
+
+    async fn or_else_soft<S2, F, Fut>(self, f: F) -> MalleableResult<T, S2, H>
+    where
+        F: FnOnce(S) -> Fut,
+        Fut: Future<Output = MalleableResult<T, S2, H>>,
+    {
+        match self.await {
+            Ok(SoftResult::Ok(t)) => Ok(SoftResult::Ok(t)),
+            Ok(SoftResult::SoftErr(s)) => f(s).await,
+            Err(h) => Err(h),
+        }
+    }
+}
+
+/// Calls `f` and awaits its future, retrying while it fails hard, for at most `attempts` calls.
+/// Ok values and soft errors are returned immediately, since retrying cannot fix a soft error.
+/// Returns the last hard error once the attempts are exhausted. `f` is always called at least once.
+pub async fn retry_hard<T, S, H, F, Fut>(attempts: usize, mut f: F) -> MalleableResult<T, S, H>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = MalleableResult<T, S, H>>,
+{
+    let mut remaining = attempts;
+    loop {
+        let result = f().await;
+        remaining = remaining.saturating_sub(1);
+        if result.is_ok() || remaining == 0 {
+            return result;
+        }
+    }
+}
+
+/// Transposes a tuple of [SoftResult]s into a [SoftResult] of a tuple, keeping the first soft error.
+/// Used by [try_join_hard](crate::try_join_hard).
+#[doc(hidden)]
+pub trait TransposeSoft<S> {
+    type Ok;
+
+    fn transpose_soft(self) -> SoftResult<Self::Ok, S>;
+}
+
+macro_rules! impl_transpose_soft {
+    ($($t:ident),+) => {
+        impl<S, $($t),+> TransposeSoft<S> for ($(SoftResult<$t, S>,)+) {
+            type Ok = ($($t,)+);
+
+            #[allow(non_snake_case)]
+            fn transpose_soft(self) -> SoftResult<Self::Ok, S> {
+                let ($($t,)+) = self;
+                SoftResult::Ok(($(match $t {
+                    SoftResult::Ok(t) => t,
+                    SoftResult::SoftErr(e) => return SoftResult::SoftErr(e),
+                },)+))
+            }
+        }
+    };
+}
+
+impl_transpose_soft!(A);
+impl_transpose_soft!(A, B);
+impl_transpose_soft!(A, B, C);
+impl_transpose_soft!(A, B, C, D);
+impl_transpose_soft!(A, B, C, D, E);
+impl_transpose_soft!(A, B, C, D, E, F);
+impl_transpose_soft!(A, B, C, D, E, F, G);
+impl_transpose_soft!(A, B, C, D, E, F, G, H);
+
+#[macro_export]
+/// Awaits several futures resolving to [MalleableResult]s concurrently, and combines their ok values into a tuple.
+/// Must be used inside an async context, and evaluates to a [MalleableResult], without short-circuiting.
+///
+/// The first hard error is returned as soon as it happens, and the remaining futures are dropped.
+/// Otherwise, every future runs to completion, since a later hard error takes precedence over a soft error.
+/// If some futures failed softly, the soft error of the first of them, in argument order, is returned.
+///
+/// All the futures must share the same soft and hard error types. Up to 8 futures are supported.
+///
+/// ```rust
+/// use try_hard::{try_join_hard, MalleableResult, SoftResult};
+///
+/// async fn user() -> MalleableResult<&'static str, String, String> {
+///     Ok(SoftResult::Ok("admin"))
+/// }
+///
+/// async fn orders() -> MalleableResult<Vec<u32>, String, String> {
+///     Ok(SoftResult::SoftErr("no orders".to_string()))
+/// }
+///
+/// async fn handler() -> MalleableResult<(&'static str, Vec<u32>), String, String> {
+///     try_join_hard!(user(), orders())
+/// }
+/// ```
+macro_rules! try_join_hard {
+    ($($fut:expr),+ $(,)?) => {
+        match $crate::__private::futures::try_join!($($fut),+) {
+            ::core::result::Result::Ok(soft_results) => ::core::result::Result::Ok(
+                $crate::__private::TransposeSoft::transpose_soft(soft_results),
+            ),
+            ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct HardError;
+
+    async fn first_step(
+        result: MalleableResult<i32, SoftError, HardError>,
+    ) -> MalleableResult<i32, SoftError, HardError> {
+        result
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(2)), true)]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Ok(SoftResult::SoftErr(SoftError)),
+        false
+    )]
+    #[case(Err(HardError), Err(HardError), false)]
+    #[tokio::test]
+    async fn check_and_then_ok(
+        #[case] first: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+        #[case] expect_second: bool,
+    ) {
+        let second_ran = &AtomicBool::new(false);
+        let result = first_step(first)
+            .and_then_ok(|x| async move {
+                second_ran.store(true, Ordering::SeqCst);
+                Ok(SoftResult::Ok(x + 1))
+            })
+            .await;
+        assert_eq!(result, expected);
+        assert_eq!(second_ran.load(Ordering::SeqCst), expect_second);
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::Ok(0)))]
+    #[case(Err(HardError), Err(HardError))]
+    #[tokio::test]
+    async fn check_or_else_soft(
+        #[case] first: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+    ) {
+        let result = first_step(first)
+            .or_else_soft(|_| async { Ok(SoftResult::Ok(0)) })
+            .await;
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(vec![Ok(SoftResult::Ok(1))], 3, Ok(SoftResult::Ok(1)), 1)]
+    #[case(vec![Ok(SoftResult::SoftErr(SoftError))], 3, Ok(SoftResult::SoftErr(SoftError)), 1)]
+    #[case(vec![Err(HardError), Err(HardError), Ok(SoftResult::Ok(1))], 3, Ok(SoftResult::Ok(1)), 3)]
+    #[case(vec![Err(HardError), Ok(SoftResult::SoftErr(SoftError))], 3, Ok(SoftResult::SoftErr(SoftError)), 2)]
+    #[case(vec![Err(HardError); 5], 3, Err(HardError), 3)]
+    #[case(vec![Err(HardError); 5], 0, Err(HardError), 1)]
+    #[tokio::test]
+    async fn check_retry_hard(
+        #[case] outcomes: Vec<MalleableResult<i32, SoftError, HardError>>,
+        #[case] attempts: usize,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected_calls: usize,
+    ) {
+        let calls = &AtomicUsize::new(0);
+        let outcomes = &outcomes;
+        let result = retry_hard(attempts, || async move {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            outcomes[call].clone()
+        })
+        .await;
+        assert_eq!(result, expected);
+        assert_eq!(calls.load(Ordering::SeqCst), expected_calls);
+    }
+
+    async fn step<T>(
+        result: MalleableResult<T, &'static str, &'static str>,
+    ) -> MalleableResult<T, &'static str, &'static str> {
+        result
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok("b")), Ok(SoftResult::Ok(())), Ok(SoftResult::Ok((1, "b", ()))))]
+    #[case(
+        Ok(SoftResult::Ok(1)),
+        Ok(SoftResult::SoftErr("second")),
+        Ok(SoftResult::SoftErr("third")),
+        Ok(SoftResult::SoftErr("second"))
+    )]
+    #[case(
+        Ok(SoftResult::SoftErr("first")),
+        Ok(SoftResult::Ok("b")),
+        Err("third"),
+        Err("third")
+    )]
+    #[case(
+        Err("first"),
+        Ok(SoftResult::SoftErr("second")),
+        Err("third"),
+        Err("first")
+    )]
+    #[tokio::test]
+    async fn check_try_join_hard(
+        #[case] a: MalleableResult<i32, &'static str, &'static str>,
+        #[case] b: MalleableResult<&'static str, &'static str, &'static str>,
+        #[case] c: MalleableResult<(), &'static str, &'static str>,
+        #[case] expected: MalleableResult<(i32, &'static str, ()), &'static str, &'static str>,
+    ) {
+        assert_eq!(try_join_hard!(step(a), step(b), step(c)), expected);
+    }
+
+    #[tokio::test]
+    async fn check_try_join_hard_cancels_on_hard_error() {
+        let never = futures::future::pending::<MalleableResult<i32, &str, &str>>();
+        let result = try_join_hard!(
+            step::<u8>(Ok(SoftResult::SoftErr("soft"))),
+            never,
+            step::<()>(Err("hard"))
+        );
+        assert_eq!(result, Err("hard"));
+    }
+}
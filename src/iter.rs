@@ -0,0 +1,312 @@
+//! Functions and types that consume iterators of [SoftResult]s.
+//! Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+
+use crate::{MalleableResult, SoftResult};
+
+/// Collects all the [SoftResult::Ok] values, or all the soft errors if any.
+///
+/// Unlike collecting into a [SoftResult], which stops at the first soft error,
+/// this consumes the whole iterator, so every soft error is reported.
+///
+/// ```rust
+/// use try_hard::{collect_soft, SoftResult};
+///
+/// let results = vec![SoftResult::Ok(1), SoftResult::SoftErr("a"), SoftResult::SoftErr("b")];
+/// assert_eq!(collect_soft(results), SoftResult::SoftErr(vec!["a", "b"]));
+/// ```
+pub fn collect_soft<T, E, I: IntoIterator<Item = SoftResult<T, E>>>(
+    iter: I,
+) -> SoftResult<Vec<T>, Vec<E>> {
+    let mut accumulator = SoftAccumulator::new();
+    accumulator.extend(iter);
+    accumulator.finish()
+}
+
+/// Returns the first [SoftResult::Ok] value, or all the soft errors if there is none.
+///
+/// This is the "any of these should work" pattern: the iterator is consumed up to the first success.
+///
+/// ```rust
+/// use try_hard::{first_ok_or_all_soft, SoftResult};
+///
+/// let mirrors = vec![SoftResult::SoftErr("down"), SoftResult::Ok("mirror 2"), SoftResult::SoftErr("slow")];
+/// assert_eq!(first_ok_or_all_soft(mirrors), SoftResult::Ok("mirror 2"));
+/// ```
+pub fn first_ok_or_all_soft<T, E, I: IntoIterator<Item = SoftResult<T, E>>>(
+    iter: I,
+) -> SoftResult<T, Vec<E>> {
+    let mut soft_errs = Vec::new();
+    for soft_result in iter {
+        match soft_result {
+            SoftResult::Ok(t) => return SoftResult::Ok(t),
+            SoftResult::SoftErr(e) => soft_errs.push(e),
+        }
+    }
+    SoftResult::SoftErr(soft_errs)
+}
+
+/// Splits an iterator of [SoftResult]s into its ok values and its soft errors, like [Iterator::partition].
+/// The whole iterator is consumed, and both vectors keep the order of the iterator.
+///
+/// ```rust
+/// use try_hard::{partition_soft, SoftResult};
+///
+/// let results = vec![SoftResult::Ok(1), SoftResult::SoftErr("a"), SoftResult::Ok(2)];
+/// assert_eq!(partition_soft(results), (vec![1, 2], vec!["a"]));
+/// ```
+pub fn partition_soft<T, E, I: IntoIterator<Item = SoftResult<T, E>>>(iter: I) -> (Vec<T>, Vec<E>) {
+    let mut accumulator = SoftAccumulator::new();
+    accumulator.extend(iter);
+    accumulator.into_parts()
+}
+
+/// Collects the [SoftResult::Ok] values up to the first soft error, returning them with that soft error if any.
+///
+/// This keeps a partial success: the iterator is consumed up to the first soft error, and the values before it are kept.
+///
+/// ```rust
+/// use try_hard::{collect_until_soft, SoftResult};
+///
+/// let results = vec![SoftResult::Ok(1), SoftResult::Ok(2), SoftResult::SoftErr("a"), SoftResult::Ok(3)];
+/// assert_eq!(collect_until_soft(results), (vec![1, 2], Some("a")));
+/// ```
+pub fn collect_until_soft<T, E, I: IntoIterator<Item = SoftResult<T, E>>>(
+    iter: I,
+) -> (Vec<T>, Option<E>) {
+    let mut oks = Vec::new();
+    for soft_result in iter {
+        match soft_result {
+            SoftResult::Ok(t) => oks.push(t),
+            SoftResult::SoftErr(e) => return (oks, Some(e)),
+        }
+    }
+    (oks, None)
+}
+
+/// Returns the first hard error if any, otherwise the first soft error if any, otherwise all the ok values.
+///
+/// This encodes the severity ordering of errors in a single pass, which stops at the first hard error.
+///
+/// ```rust
+/// use try_hard::{worst_outcome, MalleableResult, SoftResult};
+///
+/// let results: Vec<MalleableResult<i32, &str, &str>> =
+///     vec![Ok(SoftResult::Ok(1)), Ok(SoftResult::SoftErr("invalid")), Err("down"), Err("slow")];
+/// assert_eq!(worst_outcome(results), Err("down"));
+/// ```
+pub fn worst_outcome<T, S, H, I: IntoIterator<Item = MalleableResult<T, S, H>>>(
+    iter: I,
+) -> MalleableResult<Vec<T>, S, H> {
+    let mut oks = Vec::new();
+    let mut soft_err = None;
+    for result in iter {
+        match result? {
+            SoftResult::Ok(t) => oks.push(t),
+            SoftResult::SoftErr(s) => {
+                soft_err.get_or_insert(s);
+            }
+        }
+    }
+    match soft_err {
+        Some(s) => Ok(SoftResult::SoftErr(s)),
+        None => Ok(SoftResult::Ok(oks)),
+    }
+}
+
+/// Accumulates [SoftResult]s incrementally, storing the ok values and collecting the soft errors.
+/// Results are added with [SoftAccumulator::push], or with [Extend], for example once per iteration of a loop.
+///
+/// ```rust
+/// use try_hard::{SoftAccumulator, SoftResult};
+///
+/// let mut accumulator = SoftAccumulator::new();
+/// accumulator.extend([SoftResult::Ok(1), SoftResult::SoftErr("a")]);
+/// accumulator.push(SoftResult::Ok(2));
+/// assert_eq!(accumulator.oks(), &[1, 2]);
+/// assert_eq!(accumulator.finish(), SoftResult::SoftErr(vec!["a"]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoftAccumulator<T, E> {
+    oks: Vec<T>,
+    soft_errs: Vec<E>,
+}
+
+impl<T, E> SoftAccumulator<T, E> {
+    /// Creates an empty accumulator.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            oks: Vec::new(),
+            soft_errs: Vec::new(),
+        }
+    }
+
+    /// Adds a result, storing its ok value or collecting its soft error.
+    #[inline]
+    pub fn push(&mut self, soft_result: SoftResult<T, E>) {
+        match soft_result {
+            SoftResult::Ok(t) => self.oks.push(t),
+            SoftResult::SoftErr(e) => self.soft_errs.push(e),
+        }
+    }
+
+    /// Returns the ok values accumulated so far.
+    #[inline]
+    pub fn oks(&self) -> &[T] {
+        &self.oks
+    }
+
+    /// Returns the soft errors accumulated so far.
+    #[inline]
+    pub fn soft_errs(&self) -> &[E] {
+        &self.soft_errs
+    }
+
+    /// Returns the ok values and the soft errors, both in the order they were added.
+    #[inline]
+    pub fn into_parts(self) -> (Vec<T>, Vec<E>) {
+        (self.oks, self.soft_errs)
+    }
+
+    /// Returns all the ok values, or all the soft errors if any, like [collect_soft].
+    #[inline]
+    pub fn finish(self) -> SoftResult<Vec<T>, Vec<E>> {
+        if self.soft_errs.is_empty() {
+            SoftResult::Ok(self.oks)
+        } else {
+            SoftResult::SoftErr(self.soft_errs)
+        }
+    }
+}
+
+impl<T, E> Default for SoftAccumulator<T, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Extend<SoftResult<T, E>> for SoftAccumulator<T, E> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = SoftResult<T, E>>>(&mut self, iter: I) {
+        for soft_result in iter {
+            self.push(soft_result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(vec![], SoftResult::Ok(vec![]))]
+    #[case(vec![SoftResult::Ok(1), SoftResult::Ok(2)], SoftResult::Ok(vec![1, 2]))]
+    #[case(
+        vec![SoftResult::SoftErr("empty name"), SoftResult::Ok(2), SoftResult::SoftErr("invalid email")],
+        SoftResult::SoftErr(vec!["empty name", "invalid email"])
+    )]
+    fn check_collect_soft(
+        #[case] results: Vec<SoftResult<i32, &str>>,
+        #[case] expected: SoftResult<Vec<i32>, Vec<&str>>,
+    ) {
+        assert_eq!(collect_soft(results), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], SoftResult::SoftErr(vec![]))]
+    #[case(
+        vec![SoftResult::SoftErr("first"), SoftResult::SoftErr("second"), SoftResult::Ok(3), SoftResult::Ok(4)],
+        SoftResult::Ok(3)
+    )]
+    #[case(
+        vec![SoftResult::SoftErr("first"), SoftResult::SoftErr("second"), SoftResult::SoftErr("third")],
+        SoftResult::SoftErr(vec!["first", "second", "third"])
+    )]
+    fn check_first_ok_or_all_soft(
+        #[case] results: Vec<SoftResult<i32, &str>>,
+        #[case] expected: SoftResult<i32, Vec<&str>>,
+    ) {
+        assert_eq!(first_ok_or_all_soft(results), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], (vec![], vec![]))]
+    #[case(
+        vec![SoftResult::SoftErr("a"), SoftResult::Ok(1), SoftResult::SoftErr("b"), SoftResult::Ok(2)],
+        (vec![1, 2], vec!["a", "b"])
+    )]
+    fn check_partition_soft(
+        #[case] results: Vec<SoftResult<i32, &str>>,
+        #[case] expected: (Vec<i32>, Vec<&str>),
+    ) {
+        assert_eq!(partition_soft(results), expected);
+    }
+
+    #[test]
+    fn check_soft_accumulator_extend() {
+        let mut accumulator = SoftAccumulator::default();
+        for batch in [
+            vec![SoftResult::Ok(1), SoftResult::SoftErr("a")],
+            vec![],
+            vec![
+                SoftResult::SoftErr("b"),
+                SoftResult::Ok(2),
+                SoftResult::Ok(3),
+            ],
+        ] {
+            accumulator.extend(batch);
+        }
+        assert_eq!(accumulator.oks(), &[1, 2, 3]);
+        assert_eq!(accumulator.soft_errs(), &["a", "b"]);
+        assert_eq!(
+            accumulator.clone().into_parts(),
+            (vec![1, 2, 3], vec!["a", "b"])
+        );
+        assert_eq!(accumulator.finish(), SoftResult::SoftErr(vec!["a", "b"]));
+    }
+
+    #[test]
+    fn check_soft_accumulator_without_soft_errs() {
+        let mut accumulator: SoftAccumulator<i32, &str> = SoftAccumulator::new();
+        for i in 0..3 {
+            accumulator.push(SoftResult::Ok(i));
+        }
+        assert_eq!(accumulator.finish(), SoftResult::Ok(vec![0, 1, 2]));
+    }
+
+    #[rstest]
+    #[case(vec![Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(2))], Ok(SoftResult::Ok(vec![1, 2])))]
+    #[case(
+        vec![Ok(SoftResult::Ok(1)), Ok(SoftResult::SoftErr("first")), Ok(SoftResult::SoftErr("second"))],
+        Ok(SoftResult::SoftErr("first"))
+    )]
+    #[case(
+        vec![Ok(SoftResult::SoftErr("soft")), Ok(SoftResult::Ok(1)), Err("hard"), Ok(SoftResult::Ok(2))],
+        Err("hard")
+    )]
+    fn check_worst_outcome(
+        #[case] results: Vec<MalleableResult<i32, &str, &str>>,
+        #[case] expected: MalleableResult<Vec<i32>, &str, &str>,
+    ) {
+        assert_eq!(worst_outcome(results), expected);
+    }
+
+    #[rstest]
+    #[case(vec![SoftResult::Ok(1), SoftResult::Ok(2)], (vec![1, 2], None))]
+    #[case(
+        vec![SoftResult::Ok(1), SoftResult::SoftErr("first"), SoftResult::Ok(2), SoftResult::SoftErr("second")],
+        (vec![1], Some("first"))
+    )]
+    #[case(vec![SoftResult::SoftErr("first")], (vec![], Some("first")))]
+    fn check_collect_until_soft(
+        #[case] results: Vec<SoftResult<i32, &str>>,
+        #[case] expected: (Vec<i32>, Option<&str>),
+    ) {
+        assert_eq!(collect_until_soft(results), expected);
+    }
+}
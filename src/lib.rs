@@ -3,15 +3,15 @@
 //!
 //! A [MalleableResult] distinguishes errors in two categories:
 //! - Soft errors:
-//!     These are "benign" error types that shouldn't cause your application to stop.
-//!     Think of 404 errors, or any other error that was caused by the user, and not by your application.
-//!     Soft errors won't trigger `error` events when used with the `#[instrument(err)]` `tracing` macro.
+//!   These are "benign" error types that shouldn't cause your application to stop.
+//!   Think of 404 errors, or any other error that was caused by the user, and not by your application.
+//!   Soft errors won't trigger `error` events when used with the `#[instrument(err)]` `tracing` macro.
 //!
 //! - Hard errors:
-//!     These are bad.
-//!     Hard errors are in general not fault of the user, and the user is hopeless without our intervention.
-//!     Hard errors must be monitored.
-//!     Hard errors will result in `error` events when used with the `#[instrument(err)]` `tracing macro.`
+//!   These are bad.
+//!   Hard errors are in general not fault of the user, and the user is hopeless without our intervention.
+//!   Hard errors must be monitored.
+//!   Hard errors will result in `error` events when used with the `#[instrument(err)]` `tracing macro.`
 //!
 //!
 //! # How can I use it?
@@ -64,15 +64,134 @@
 //!
 //! ```
 //!
+//! The [prelude] re-exports the types, extension traits and macros, for `use try_hard::prelude::*;`.
 //!
+//! # Features
+//!
+//! The core types and macros are `no_std` compatible: disable the default features to use them without `std`.
+//!
+//! - `std` (default): enables `std` support, implies `alloc`. Allows `main` to return a [MalleableResult].
+//! - `alloc`: enables the items that need an allocator, without `std`.
+//! - `nightly`: implements the unstable `Try` trait for [SoftResult], so `?` can be used instead of [try_soft].
+//! - `tracing` (default): implies `std`, provides macros that emit `tracing` events, such as [try_soft_traced].
+//! - `log_json`: implies `tracing` and `serde`, provides [try_hard_json], which logs errors serialized as JSON.
+//! - `futures`: provides [MalleableFutureExt] and [MalleableStreamExt], with async combinators for futures and streams of [MalleableResult]s, [retry_hard] and [try_join_hard].
+//! - `tokio`: implies `std`, provides [with_hard_timeout], converting an elapsed `tokio` timeout into a hard error.
+//! - `anyhow`: provides [AnyhowExt] and [try_hard_anyhow], to use `anyhow::Error` as the hard error.
+//! - `eyre`: provides [EyreExt] and [try_hard_eyre], to use `eyre::Report` as the hard error.
+//! - `axum`: implements `IntoResponse` for [SoftResult], so handlers can return a [MalleableResult].
+//!   With `serde`, also provides [SoftResult::into_response_parts], building a JSON response.
+//! - `macros`: provides the `#[malleable]` attribute, which wraps the returned values of a function into `Ok(SoftResult::Ok(_))`.
+//!   It is not named `#[try_hard]`, since that would clash with the [try_hard] macro.
+//! - `serde`: implements `Serialize` and `Deserialize` for [SoftResult], as `{"Ok": _}` or `{"SoftErr": _}`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2, try_trait_v2_residual))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod assert;
+#[cfg(feature = "alloc")]
+mod builder;
+mod error;
+mod ext;
+#[cfg(feature = "futures")]
+mod future;
+#[cfg(feature = "alloc")]
+mod iter;
+pub mod make;
+#[cfg(feature = "alloc")]
+mod message;
+#[cfg(feature = "nightly")]
+mod nightly;
+pub mod prelude;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+mod report;
+#[cfg(feature = "axum")]
+mod response;
+mod status;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(all(test, feature = "tracing"))]
+mod test_support;
+#[cfg(feature = "tokio")]
+mod timeout;
+#[cfg(feature = "tracing")]
+mod traced;
+
+#[cfg(feature = "alloc")]
+pub use builder::SoftResultBuilder;
+#[cfg(feature = "alloc")]
+pub use error::SoftErrors;
+pub use error::{Escalated, HardOrSoft};
+pub use ext::{FlattenMalleable, MalleableResultExt, OptionExt, ResultExt};
+#[cfg(feature = "futures")]
+pub use future::{MalleableFutureExt, retry_hard};
+#[cfg(feature = "alloc")]
+pub use iter::{
+    SoftAccumulator, collect_soft, collect_until_soft, first_ok_or_all_soft, partition_soft,
+    worst_outcome,
+};
+#[cfg(feature = "alloc")]
+pub use message::SoftMessage;
+#[cfg(feature = "anyhow")]
+pub use report::AnyhowExt;
+#[cfg(feature = "eyre")]
+pub use report::EyreExt;
+pub use status::SoftStatus;
+#[cfg(feature = "futures")]
+pub use stream::{MalleableStreamExt, SplitHard};
+#[cfg(feature = "tokio")]
+pub use timeout::with_hard_timeout;
+#[cfg(feature = "tracing")]
+pub use traced::observe_malleable;
+#[cfg(feature = "macros")]
+pub use try_hard_macros::malleable;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assert::AssertSoft;
+    #[cfg(feature = "futures")]
+    pub use crate::future::TransposeSoft;
+    #[cfg(feature = "anyhow")]
+    pub use anyhow;
+    #[cfg(feature = "eyre")]
+    pub use eyre;
+    #[cfg(feature = "futures")]
+    pub use futures;
+    #[cfg(feature = "log_json")]
+    pub use serde_json;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+}
 
 /// A hard result contains a hard error in its [Err] variant, and a [SoftResult] in its [Ok] variant.
 /// A hard error is a catastrophic failure, that should be avoided at all costs.
 pub type MalleableResult<T, SoftError, HardError> = Result<SoftResult<T, SoftError>, HardError>;
 
 /// A [SoftResult], should only contain errors if these errors are benign, and can be presented to the user as a valid response.
+///
+/// `SoftResult` is deliberately exhaustive, like [Result]: it models exactly two outcomes, and matching on both variants is part of its API.
+/// Marking it `#[non_exhaustive]` would force a wildcard arm on every external match, and break the macros of this crate, which expand to exhaustive matches in the calling crate.
+///
+/// Matching both variants, without a wildcard arm, is supported:
+///
+/// ```rust
+/// use try_hard::SoftResult;
+///
+/// fn describe(soft_result: SoftResult<i32, &str>) -> String {
+///     match soft_result {
+///         SoftResult::Ok(x) => format!("ok {x}"),
+///         SoftResult::SoftErr(e) => format!("soft error {e}"),
+///     }
+/// }
+///
+/// assert_eq!(describe(SoftResult::Ok(1)), "ok 1");
+/// assert_eq!(describe(SoftResult::SoftErr("missing")), "soft error missing");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use = "this `SoftResult` may be a soft error, which should be handled, or explicitly ignored with `discard`"]
 pub enum SoftResult<T, E> {
     /// The Ok variant should be used like the core result [Ok].
     Ok(T),
@@ -81,14 +200,884 @@ pub enum SoftResult<T, E> {
     SoftErr(E),
 }
 
+impl<T, E> SoftResult<T, E> {
+    /// Maps a `SoftResult<T, E>` to `SoftResult<U, E>` by applying `f` to the [SoftResult::Ok] value.
+    /// A [SoftResult::SoftErr] is left untouched, like [Result::map].
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> SoftResult<U, E> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(f(t)),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Maps a `SoftResult<T, E>` to `SoftResult<T, F>` by applying `g` to the [SoftResult::SoftErr] value.
+    /// A [SoftResult::Ok] is left untouched, like [Result::map_err].
+    #[inline]
+    pub fn map_soft_err<F, G: FnOnce(E) -> F>(self, g: G) -> SoftResult<T, F> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(g(e)),
+        }
+    }
+
+    /// Calls `f` if the result is [SoftResult::Ok], otherwise returns the [SoftResult::SoftErr] value.
+    /// This short-circuits on the first soft error, like [Result::and_then].
+    #[inline]
+    pub fn and_then<U, F: FnOnce(T) -> SoftResult<U, E>>(self, f: F) -> SoftResult<U, E> {
+        match self {
+            SoftResult::Ok(t) => f(t),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Calls `op` if the result is [SoftResult::SoftErr], otherwise returns the [SoftResult::Ok] value.
+    /// This can be used to recover from a soft error, like [Result::or_else].
+    #[inline]
+    pub fn or_else<F, O: FnOnce(E) -> SoftResult<T, F>>(self, op: O) -> SoftResult<T, F> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(e) => op(e),
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a [SoftResult::SoftErr], with a message containing the soft error.
+    #[inline]
+    #[track_caller]
+    pub fn unwrap(self) -> T
+    where
+        E: core::fmt::Debug,
+    {
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(e) => panic!("called SoftResult::unwrap on a SoftErr value: {e:?}"),
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a [SoftResult::SoftErr], with a message containing `msg` and the soft error.
+    #[inline]
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T
+    where
+        E: core::fmt::Debug,
+    {
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(e) => panic!("{msg}: {e:?}"),
+        }
+    }
+
+    /// Returns the contained [SoftResult::SoftErr] value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a [SoftResult::Ok], with a message containing the ok value.
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_soft_err(self) -> E
+    where
+        T: core::fmt::Debug,
+    {
+        match self {
+            SoftResult::Ok(t) => panic!("called SoftResult::unwrap_soft_err on an Ok value: {t:?}"),
+            SoftResult::SoftErr(e) => e,
+        }
+    }
+
+    /// Returns the contained [SoftResult::SoftErr] value.
+    /// Useful in tests asserting that a soft error occurred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a [SoftResult::Ok], with a message containing `msg` and the ok value.
+    #[inline]
+    #[track_caller]
+    pub fn expect_soft_err(self, msg: &str) -> E
+    where
+        T: core::fmt::Debug,
+    {
+        match self {
+            SoftResult::Ok(t) => panic!("{msg}: {t:?}"),
+            SoftResult::SoftErr(e) => e,
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value or the provided `default`.
+    /// The `default` is eagerly evaluated, use [SoftResult::unwrap_or_else] to evaluate it lazily.
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(_) => default,
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value or computes it from the soft error with `f`.
+    #[inline]
+    pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, f: F) -> T {
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(e) => f(e),
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value or the [Default] value of `T`.
+    #[inline]
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(_) => T::default(),
+        }
+    }
+
+    /// Builds a [SoftResult::Ok], usable in `const` contexts.
+    #[inline]
+    pub const fn ok_const(t: T) -> Self {
+        SoftResult::Ok(t)
+    }
+
+    /// Builds a [SoftResult::SoftErr], usable in `const` contexts.
+    #[inline]
+    pub const fn soft_const(e: E) -> Self {
+        SoftResult::SoftErr(e)
+    }
+
+    /// Returns `true` if the result is [SoftResult::Ok].
+    #[inline]
+    pub const fn is_ok(&self) -> bool {
+        matches!(self, SoftResult::Ok(_))
+    }
+
+    /// Returns `true` if the result is [SoftResult::SoftErr].
+    #[inline]
+    pub const fn is_soft_err(&self) -> bool {
+        matches!(self, SoftResult::SoftErr(_))
+    }
+
+    /// Returns `true` if the result is [SoftResult::Ok] and its value matches the predicate `f`.
+    /// Unlike [Result::is_ok_and], the value is borrowed, so the result is not consumed.
+    #[inline]
+    pub fn is_ok_and<F: FnOnce(&T) -> bool>(&self, f: F) -> bool {
+        match self {
+            SoftResult::Ok(t) => f(t),
+            SoftResult::SoftErr(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is [SoftResult::SoftErr] and its error matches the predicate `f`.
+    /// Unlike [Result::is_err_and], the error is borrowed, so the result is not consumed.
+    #[inline]
+    pub fn is_soft_err_and<F: FnOnce(&E) -> bool>(&self, f: F) -> bool {
+        match self {
+            SoftResult::Ok(_) => false,
+            SoftResult::SoftErr(e) => f(e),
+        }
+    }
+
+    /// Converts the [SoftResult] into an [Option], discarding the soft error, like [Result::ok].
+    #[inline]
+    #[must_use]
+    pub fn ok(self) -> Option<T> {
+        match self {
+            SoftResult::Ok(t) => Some(t),
+            SoftResult::SoftErr(_) => None,
+        }
+    }
+
+    /// Converts the [SoftResult] into an [Option] of the soft error, discarding the ok value, like [Result::err].
+    #[inline]
+    #[must_use]
+    pub fn soft_err(self) -> Option<E> {
+        match self {
+            SoftResult::Ok(_) => None,
+            SoftResult::SoftErr(e) => Some(e),
+        }
+    }
+
+    /// Converts from `&SoftResult<T, E>` to `SoftResult<&T, &E>`, like [Result::as_ref].
+    #[inline]
+    pub fn as_ref(&self) -> SoftResult<&T, &E> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Converts from `&mut SoftResult<T, E>` to `SoftResult<&mut T, &mut E>`, like [Result::as_mut].
+    #[inline]
+    pub fn as_mut(&mut self) -> SoftResult<&mut T, &mut E> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Returns an iterator over the [SoftResult::Ok] value.
+    /// The iterator yields one value if the result is [SoftResult::Ok], otherwise none.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_ref().ok().into_iter()
+    }
+
+    /// Returns a mutable iterator over the [SoftResult::Ok] value.
+    /// The iterator yields one value if the result is [SoftResult::Ok], otherwise none.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.as_mut().ok().into_iter()
+    }
+
+    /// Converts the [SoftResult] into a core [Result], mapping [SoftResult::SoftErr] to [Err].
+    #[inline]
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            SoftResult::Ok(t) => Ok(t),
+            SoftResult::SoftErr(e) => Err(e),
+        }
+    }
+
+    /// Promotes the soft error into a hard error, by converting it with `f`.
+    /// Use this when a soft error turns out to be fatal in the current context.
+    #[doc(alias = "ok_or_hard")]
+    #[inline]
+    pub fn harden<H, F: FnOnce(E) -> H>(self, f: F) -> Result<T, H> {
+        match self {
+            SoftResult::Ok(t) => Ok(t),
+            SoftResult::SoftErr(e) => Err(f(e)),
+        }
+    }
+
+    /// Promotes the soft error into a hard error, wrapping it in [Escalated].
+    /// This is `harden(Escalated)`, and what [try_escalate] returns on a soft error.
+    #[inline]
+    pub fn escalate(self) -> Result<T, Escalated<E>> {
+        self.harden(Escalated)
+    }
+
+    /// Returns the provided `default` if the result is a [SoftResult::SoftErr],
+    /// otherwise applies `f` to the [SoftResult::Ok] value, like [Result::map_or].
+    #[inline]
+    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+        match self {
+            SoftResult::Ok(t) => f(t),
+            SoftResult::SoftErr(_) => default,
+        }
+    }
+
+    /// Maps the result to `U` by applying `default` to the [SoftResult::SoftErr] value,
+    /// or `f` to the [SoftResult::Ok] value, like [Result::map_or_else].
+    #[inline]
+    pub fn map_or_else<U, D: FnOnce(E) -> U, F: FnOnce(T) -> U>(self, default: D, f: F) -> U {
+        match self {
+            SoftResult::Ok(t) => f(t),
+            SoftResult::SoftErr(e) => default(e),
+        }
+    }
+
+    /// Calls `f` with a reference to the [SoftResult::Ok] value, then returns the result unchanged.
+    #[inline]
+    pub fn inspect<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let SoftResult::Ok(t) = &self {
+            f(t);
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the [SoftResult::SoftErr] value, then returns the result unchanged.
+    #[inline]
+    pub fn inspect_soft_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let SoftResult::SoftErr(e) = &self {
+            f(e);
+        }
+        self
+    }
+
+    /// Pairs the soft error with its HTTP status code, as given by [SoftStatus].
+    #[inline]
+    pub fn with_status(self) -> SoftResult<T, (u16, E)>
+    where
+        E: SoftStatus,
+    {
+        self.map_soft_err(|e| (e.status_code(), e))
+    }
+
+    /// Returns `true` if the result is a [SoftResult::Ok] value equal to `x`.
+    #[inline]
+    pub fn contains<U: PartialEq<T>>(&self, x: &U) -> bool {
+        match self {
+            SoftResult::Ok(t) => x == t,
+            SoftResult::SoftErr(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is a [SoftResult::SoftErr] value equal to `e`.
+    #[inline]
+    pub fn contains_soft_err<F: PartialEq<E>>(&self, e: &F) -> bool {
+        match self {
+            SoftResult::Ok(_) => false,
+            SoftResult::SoftErr(soft_err) => e == soft_err,
+        }
+    }
+
+    /// Collapses both variants into a single `U`, applying `ok` to the [SoftResult::Ok] value
+    /// or `soft` to the [SoftResult::SoftErr] value.
+    /// This is [SoftResult::map_or_else], with the closures in variant order.
+    #[inline]
+    pub fn fold<U>(self, ok: impl FnOnce(T) -> U, soft: impl FnOnce(E) -> U) -> U {
+        match self {
+            SoftResult::Ok(t) => ok(t),
+            SoftResult::SoftErr(e) => soft(e),
+        }
+    }
+
+    /// Demotes a [SoftResult::Ok] value into the soft error `err` if it does not match the `predicate`.
+    /// A [SoftResult::SoftErr] is returned unchanged.
+    #[inline]
+    pub fn filter<F: FnOnce(&T) -> bool>(self, err: E, predicate: F) -> Self {
+        match self {
+            SoftResult::Ok(t) if !predicate(&t) => SoftResult::SoftErr(err),
+            soft_result => soft_result,
+        }
+    }
+
+    /// Demotes a [SoftResult::Ok] value into a soft error if `check` returns one.
+    /// Unlike [SoftResult::filter], the soft error is computed lazily.
+    /// A [SoftResult::SoftErr] is returned unchanged.
+    #[inline]
+    pub fn filter_with<F: FnOnce(&T) -> Option<E>>(self, check: F) -> Self {
+        match self {
+            SoftResult::Ok(t) => match check(&t) {
+                Some(e) => SoftResult::SoftErr(e),
+                None => SoftResult::Ok(t),
+            },
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Combines two results into a tuple, if both are [SoftResult::Ok].
+    /// Otherwise returns the first [SoftResult::SoftErr], preferring the soft error of `self`.
+    #[inline]
+    pub fn zip<U>(self, other: SoftResult<U, E>) -> SoftResult<(T, U), E> {
+        match (self, other) {
+            (SoftResult::Ok(t), SoftResult::Ok(u)) => SoftResult::Ok((t, u)),
+            (SoftResult::SoftErr(e), _) | (_, SoftResult::SoftErr(e)) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Converts an [Option] into a `SoftResult`, mapping [None] to the soft error `err`.
+    /// `err` is evaluated eagerly, use [SoftResult::from_option_with] to compute it lazily.
+    #[inline]
+    pub fn from_option(opt: Option<T>, err: E) -> Self {
+        match opt {
+            Some(t) => SoftResult::Ok(t),
+            None => SoftResult::SoftErr(err),
+        }
+    }
+
+    /// Converts an [Option] into a `SoftResult`, mapping [None] to the soft error returned by `err`.
+    #[inline]
+    pub fn from_option_with<F: FnOnce() -> E>(opt: Option<T>, err: F) -> Self {
+        match opt {
+            Some(t) => SoftResult::Ok(t),
+            None => SoftResult::SoftErr(err()),
+        }
+    }
+
+    /// Replaces a [SoftResult::SoftErr] with `Ok(f())`, then returns a mutable reference to the contained value,
+    /// like [Option::get_or_insert_with].
+    /// `f` is not called if the result is already [SoftResult::Ok].
+    #[inline]
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if let SoftResult::SoftErr(_) = self {
+            *self = SoftResult::Ok(f());
+        }
+        match self {
+            SoftResult::Ok(t) => t,
+            SoftResult::SoftErr(_) => unreachable!(),
+        }
+    }
+
+    /// Lifts the result into a [MalleableResult] that has not failed hard, `Ok(self)`.
+    ///
+    /// ```rust
+    /// use try_hard::{MalleableResult, SoftResult};
+    ///
+    /// fn lookup(id: u32) -> MalleableResult<&'static str, String, std::io::Error> {
+    ///     let found = match id {
+    ///         1 => SoftResult::Ok("admin"),
+    ///         _ => SoftResult::SoftErr(format!("no user {id}")),
+    ///     };
+    ///     found.into_malleable()
+    /// }
+    ///
+    /// assert!(matches!(lookup(2), Ok(SoftResult::SoftErr(_))));
+    /// ```
+    #[inline]
+    pub fn into_malleable<H>(self) -> MalleableResult<T, E, H> {
+        Ok(self)
+    }
+
+    /// Explicitly ignores the result, documenting that a soft error is deliberately not handled.
+    /// This silences the `#[must_use]` warning, like `let _ = ...`, but is easier to search for.
+    #[inline]
+    pub fn discard(self) {}
+
+    /// Maps a `SoftResult<T, E>` to a `SoftResult<U, F>`, applying `ok` to a [SoftResult::Ok] value or `soft` to a soft error.
+    /// This is equivalent to chaining [SoftResult::map] and [SoftResult::map_soft_err].
+    #[inline]
+    pub fn map_both<U, F, O: FnOnce(T) -> U, G: FnOnce(E) -> F>(
+        self,
+        ok: O,
+        soft: G,
+    ) -> SoftResult<U, F> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(ok(t)),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(soft(e)),
+        }
+    }
+
+    /// Runs `check` on `subject`, accumulating its soft error into this result.
+    /// If both this result and `check` are soft errors, they are combined with `merge`, in that order.
+    /// The check runs even if this result is already a soft error, so chained validations report every complaint.
+    ///
+    /// Unlike a check of the ok value, with a `FnOnce(&T)`, the subject is passed separately:
+    /// once a check has failed, the result no longer holds a value to validate,
+    /// and the later checks must still run for their soft errors to be merged.
+    ///
+    /// ```rust
+    /// use try_hard::SoftResult;
+    ///
+    /// fn non_empty(name: &str) -> SoftResult<(), String> {
+    ///     if name.is_empty() {
+    ///         return SoftResult::SoftErr("empty".to_string());
+    ///     }
+    ///     SoftResult::Ok(())
+    /// }
+    ///
+    /// fn capitalized(name: &str) -> SoftResult<(), String> {
+    ///     if !name.starts_with(char::is_uppercase) {
+    ///         return SoftResult::SoftErr("not capitalized".to_string());
+    ///     }
+    ///     SoftResult::Ok(())
+    /// }
+    ///
+    /// let merge = |a: String, b: String| format!("{a}, {b}");
+    /// let validated = SoftResult::Ok("")
+    ///     .accumulate_check("", non_empty, merge)
+    ///     .accumulate_check("", capitalized, merge);
+    /// assert_eq!(validated, SoftResult::SoftErr("empty, not capitalized".to_string()));
+    /// ```
+    #[inline]
+    pub fn accumulate_check<V, F, M>(self, subject: &V, check: F, merge: M) -> Self
+    where
+        V: ?Sized,
+        F: FnOnce(&V) -> SoftResult<(), E>,
+        M: FnOnce(E, E) -> E,
+    {
+        match (self, check(subject)) {
+            (soft_result, SoftResult::Ok(())) => soft_result,
+            (SoftResult::Ok(_), SoftResult::SoftErr(e)) => SoftResult::SoftErr(e),
+            (SoftResult::SoftErr(e), SoftResult::SoftErr(f)) => SoftResult::SoftErr(merge(e, f)),
+        }
+    }
+
+    /// Returns the contained [SoftResult::Ok] value, without checking that the value is not a [SoftResult::SoftErr].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on a [SoftResult::SoftErr] is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_unchecked(self) -> T {
+        match self {
+            SoftResult::Ok(t) => t,
+            // SAFETY: the caller guarantees that the result is not a soft error.
+            SoftResult::SoftErr(_) => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    /// Returns the contained [SoftResult::SoftErr] value, without checking that the value is not a [SoftResult::Ok].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on a [SoftResult::Ok] is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[inline]
+    #[track_caller]
+    pub unsafe fn unwrap_soft_err_unchecked(self) -> E {
+        match self {
+            // SAFETY: the caller guarantees that the result is a soft error.
+            SoftResult::Ok(_) => unsafe { core::hint::unreachable_unchecked() },
+            SoftResult::SoftErr(e) => e,
+        }
+    }
+
+    /// Adds context to a soft error, like `anyhow`'s `context`, by prepending `ctx` to its [Display](core::fmt::Display).
+    /// The combined message is `{ctx}: {soft error}`. Requires the `alloc` feature.
+    ///
+    /// ```rust
+    /// use try_hard::SoftResult;
+    ///
+    /// let user: SoftResult<(), &str> = SoftResult::SoftErr("not found");
+    /// assert_eq!(
+    ///     user.with_soft_context("while loading user"),
+    ///     SoftResult::SoftErr("while loading user: not found".to_string())
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn with_soft_context<C: core::fmt::Display>(
+        self,
+        ctx: C,
+    ) -> SoftResult<T, alloc::string::String>
+    where
+        E: core::fmt::Display,
+    {
+        self.map_soft_err(|e| alloc::format!("{ctx}: {e}"))
+    }
+
+    /// Recovers from a soft error with the default value, `SoftResult::Ok(T::default())`.
+    /// Useful when a missing optional resource should be treated as empty.
+    #[inline]
+    pub fn or_default_soft(self) -> Self
+    where
+        T: Default,
+    {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(_) => SoftResult::Ok(T::default()),
+        }
+    }
+
+    /// Returns an iterator over the soft error, yielding one item for a [SoftResult::SoftErr], and none for a [SoftResult::Ok].
+    #[inline]
+    pub fn iter_soft_err(&self) -> impl Iterator<Item = &E> {
+        self.as_ref().soft_err().into_iter()
+    }
+
+    /// Works like [SoftResult::inspect], but only in debug builds.
+    /// In release builds, without `debug_assertions`, `f` is never called and the check is compiled away.
+    #[inline]
+    pub fn tap_dbg<F: FnOnce(&T)>(self, f: F) -> Self {
+        if cfg!(debug_assertions) {
+            return self.inspect(f);
+        }
+        self
+    }
+
+    /// Works like [SoftResult::inspect_soft_err], but only in debug builds.
+    /// In release builds, without `debug_assertions`, `f` is never called and the check is compiled away.
+    #[inline]
+    pub fn tap_soft_dbg<F: FnOnce(&E)>(self, f: F) -> Self {
+        if cfg!(debug_assertions) {
+            return self.inspect_soft_err(f);
+        }
+        self
+    }
+
+    /// Replaces the result with `SoftResult::Ok(value)`, returning the old result, like [core::mem::replace].
+    #[inline]
+    pub fn replace(&mut self, value: T) -> Self {
+        core::mem::replace(self, SoftResult::Ok(value))
+    }
+
+    /// Takes the result out, leaving a default soft error, `SoftResult::SoftErr(E::default())`, in its place.
+    #[inline]
+    pub fn take(&mut self) -> Self
+    where
+        E: Default,
+    {
+        core::mem::replace(self, SoftResult::SoftErr(E::default()))
+    }
+
+    /// Returns `res` if the result is [SoftResult::Ok], otherwise returns the soft error of `self`, like [Result::and].
+    /// `res` is evaluated eagerly, use [SoftResult::and_then] to compute it lazily.
+    #[inline]
+    pub fn and<U>(self, res: SoftResult<U, E>) -> SoftResult<U, E> {
+        match self {
+            SoftResult::Ok(_) => res,
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+
+    /// Returns `res` if the result is [SoftResult::SoftErr], otherwise returns the ok value of `self`, like [Result::or].
+    /// `res` is evaluated eagerly, use [SoftResult::or_else] to compute it lazily.
+    #[inline]
+    pub fn or<F>(self, res: SoftResult<T, F>) -> SoftResult<T, F> {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t),
+            SoftResult::SoftErr(_) => res,
+        }
+    }
+
+    /// Mutates the soft error in place with `f`, without moving it out of the result.
+    /// Useful to reuse an existing error buffer, such as a [String], instead of allocating a new error with [SoftResult::map_soft_err].
+    /// `f` is not called if the result is [SoftResult::Ok].
+    #[inline]
+    pub fn map_soft_err_in_place<F: FnOnce(&mut E)>(&mut self, f: F) {
+        if let SoftResult::SoftErr(e) = self {
+            f(e);
+        }
+    }
+}
+
+impl<T, E> SoftResult<Option<T>, E> {
+    /// Transposes a `SoftResult` of an [Option] into an [Option] of a `SoftResult`, like [Result::transpose].
+    ///
+    /// `Ok(None)` is mapped to [None], `Ok(Some(_))` and `SoftErr(_)` are mapped to [Some].
+    #[inline]
+    pub fn transpose(self) -> Option<SoftResult<T, E>> {
+        match self {
+            SoftResult::Ok(Some(t)) => Some(SoftResult::Ok(t)),
+            SoftResult::Ok(None) => None,
+            SoftResult::SoftErr(e) => Some(SoftResult::SoftErr(e)),
+        }
+    }
+
+    /// Treats a missing value as a soft error, turning `Ok(None)` into `SoftErr(err)` and `Ok(Some(t))` into `Ok(t)`.
+    /// An existing [SoftResult::SoftErr] is preserved.
+    ///
+    /// `err` is evaluated eagerly.
+    #[inline]
+    pub fn ok_some_or(self, err: E) -> SoftResult<T, E> {
+        match self {
+            SoftResult::Ok(Some(t)) => SoftResult::Ok(t),
+            SoftResult::Ok(None) => SoftResult::SoftErr(err),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+}
+
+impl<T, E> SoftResult<SoftResult<T, E>, E> {
+    /// Collapses a nested `SoftResult` into a single one, like [Result::flatten].
+    /// An outer [SoftResult::SoftErr] is preserved, and an inner result is returned as is.
+    #[inline]
+    pub fn flatten(self) -> SoftResult<T, E> {
+        match self {
+            SoftResult::Ok(inner) => inner,
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e),
+        }
+    }
+}
+
+impl<A, B, E: Clone> SoftResult<(A, B), E> {
+    /// Unzips a `SoftResult` of a pair into a pair of `SoftResult`s, like [Option::unzip].
+    /// A soft error is cloned into both results.
+    #[inline]
+    pub fn unzip(self) -> (SoftResult<A, E>, SoftResult<B, E>) {
+        match self {
+            SoftResult::Ok((a, b)) => (SoftResult::Ok(a), SoftResult::Ok(b)),
+            SoftResult::SoftErr(e) => (SoftResult::SoftErr(e.clone()), SoftResult::SoftErr(e)),
+        }
+    }
+}
+
+impl<T, E> SoftResult<&T, &E> {
+    /// Maps a `SoftResult<&T, &E>` to a `SoftResult<T, E>` by cloning the contents, like [Result::cloned].
+    #[inline]
+    pub fn cloned(self) -> SoftResult<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(t.clone()),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(e.clone()),
+        }
+    }
+
+    /// Maps a `SoftResult<&T, &E>` to a `SoftResult<T, E>` by copying the contents, like [Result::copied].
+    #[inline]
+    pub fn copied(self) -> SoftResult<T, E>
+    where
+        T: Copy,
+        E: Copy,
+    {
+        match self {
+            SoftResult::Ok(t) => SoftResult::Ok(*t),
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(*e),
+        }
+    }
+}
+
+impl<T, E> IntoIterator for SoftResult<T, E> {
+    type Item = T;
+    type IntoIter = core::option::IntoIter<T>;
+
+    /// Returns a consuming iterator over the [SoftResult::Ok] value.
+    /// The iterator yields one value if the result is [SoftResult::Ok], otherwise none.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.ok().into_iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a SoftResult<T, E> {
+    type Item = &'a T;
+    type IntoIter = core::option::IntoIter<&'a T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().ok().into_iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a mut SoftResult<T, E> {
+    type Item = &'a mut T;
+    type IntoIter = core::option::IntoIter<&'a mut T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().ok().into_iter()
+    }
+}
+
+/// Converts a core [Result] into a [SoftResult], treating its [Err] as a soft error.
+///
+/// ```rust
+/// use try_hard::SoftResult;
+///
+/// let parsed: SoftResult<u8, _> = "256".parse::<u8>().into();
+/// assert!(matches!(parsed, SoftResult::SoftErr(_)));
+/// ```
+impl<T, E> From<Result<T, E>> for SoftResult<T, E> {
+    #[inline]
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(t) => SoftResult::Ok(t),
+            Err(e) => SoftResult::SoftErr(e),
+        }
+    }
+}
+
+/// Converts a [SoftResult] into a core [Result], like [SoftResult::into_result].
+///
+/// ```rust
+/// use try_hard::SoftResult;
+///
+/// let result: Result<i32, &str> = SoftResult::SoftErr("not found").into();
+/// assert_eq!(result, Err("not found"));
+/// ```
+impl<T, E> From<SoftResult<T, E>> for Result<T, E> {
+    #[inline]
+    fn from(soft_result: SoftResult<T, E>) -> Self {
+        soft_result.into_result()
+    }
+}
+
+/// Compares a [SoftResult] with a core [Result], where [SoftResult::SoftErr] corresponds to [Err].
+///
+/// ```rust
+/// use try_hard::SoftResult;
+///
+/// assert_eq!(SoftResult::<i32, &str>::Ok(5), Ok(5));
+/// assert_ne!(SoftResult::<i32, &str>::SoftErr("not found"), Ok(5));
+/// ```
+impl<T: PartialEq, E: PartialEq> PartialEq<Result<T, E>> for SoftResult<T, E> {
+    #[inline]
+    fn eq(&self, other: &Result<T, E>) -> bool {
+        match (self, other) {
+            (SoftResult::Ok(t), Ok(u)) => t == u,
+            (SoftResult::SoftErr(e), Err(f)) => e == f,
+            _ => false,
+        }
+    }
+}
+
+/// Collects an iterator of [SoftResult]s into a single [SoftResult], like [Result]'s [FromIterator].
+/// Stops at the first [SoftResult::SoftErr] and returns it, without consuming the rest of the iterator.
+/// Otherwise, all [SoftResult::Ok] values are collected into `V`.
+impl<T, E, V: FromIterator<T>> FromIterator<SoftResult<T, E>> for SoftResult<V, E> {
+    fn from_iter<I: IntoIterator<Item = SoftResult<T, E>>>(iter: I) -> Self {
+        let mut soft_err = None;
+        let collected = iter
+            .into_iter()
+            .map_while(|soft_result| match soft_result {
+                SoftResult::Ok(t) => Some(t),
+                SoftResult::SoftErr(e) => {
+                    soft_err = Some(e);
+                    None
+                }
+            })
+            .fuse()
+            .collect();
+        match soft_err {
+            Some(e) => SoftResult::SoftErr(e),
+            None => SoftResult::Ok(collected),
+        }
+    }
+}
+
+/// The default [SoftResult] is a successful default value, `SoftResult::Ok(T::default())`.
+impl<T: Default, E> Default for SoftResult<T, E> {
+    #[inline]
+    fn default() -> Self {
+        SoftResult::Ok(T::default())
+    }
+}
+
+/// Displays `ok` for a [SoftResult::Ok], and `soft error: {soft error}` for a [SoftResult::SoftErr].
+impl<T, E: core::fmt::Display> core::fmt::Display for SoftResult<T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SoftResult::Ok(_) => write!(f, "ok"),
+            SoftResult::SoftErr(e) => write!(f, "soft error: {e}"),
+        }
+    }
+}
+
+/// Allows `main` to return a [SoftResult], or a [MalleableResult] through the [Termination](std::process::Termination) implementation of [Result].
+/// A soft error is a valid response: its [Debug](core::fmt::Debug) is printed to stdout, and the process exits successfully.
+/// A hard error is printed to stderr, and the process exits with a failure code, like any [Result].
+///
+/// ```rust,no_run
+/// use try_hard::{MalleableResult, SoftResult};
+///
+/// fn main() -> MalleableResult<(), String, std::io::Error> {
+///     Ok(SoftResult::SoftErr("nothing to do".to_string()))
+/// }
+/// ```
+#[cfg(feature = "std")]
+impl<T: std::process::Termination, E: core::fmt::Debug> std::process::Termination
+    for SoftResult<T, E>
+{
+    fn report(self) -> std::process::ExitCode {
+        match self {
+            SoftResult::Ok(t) => t.report(),
+            SoftResult::SoftErr(e) => {
+                println!("{e:?}");
+                std::process::ExitCode::SUCCESS
+            }
+        }
+    }
+}
+
 #[macro_export]
 /// The `try_soft` macro does the job of the `?` operator: extract the [SoftResult::Ok] Value, without short-circuiting.
 /// It will short-circuit in case of a [SoftResult::SoftErr], returning a `MalleableResult::Ok(SoftResult::SoftErr(_))`.
+/// Like `?`, the soft error is converted with [Into] into the declared soft error type.
 macro_rules! try_soft {
     ($e:expr) => {
         match $e {
-            SoftResult::Ok(t) => t,
-            SoftResult::SoftErr(e) => return Result::Ok(SoftResult::SoftErr(e)),
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(e) => {
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
         }
     };
 }
@@ -98,45 +1087,245 @@ macro_rules! try_soft {
 /// It will short-circuit case or errors:
 /// - In case of [SoftResult::SoftErr], returning a `MalleableResult::Ok(SoftResult::SoftErr(_))`.
 /// - In case of [MalleableResult]::Err, returning a `MalleableResult::Err(_)`.
+///
+/// Like `?`, the soft and hard errors are converted with [Into] into the declared error types.
 macro_rules! try_hard {
     ($e:expr) => {
         match $e {
-            Result::Ok(t) => try_soft!(t),
-            Result::Err(e) => return Result::Err(e),
+            ::core::result::Result::Ok(t) => $crate::try_soft!(t),
+            ::core::result::Result::Err(e) => {
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
         }
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use rstest::rstest;
-    use tracing::instrument;
-
-    use super::*;
+#[macro_export]
+/// The `soft_bail` macro returns early with a soft error, `MalleableResult::Ok(SoftResult::SoftErr(_))`.
+/// The error is converted with [Into] into the declared soft error type.
+macro_rules! soft_bail {
+    ($e:expr) => {
+        return ::core::result::Result::Ok($crate::SoftResult::SoftErr(::core::convert::Into::into(
+            $e,
+        )))
+    };
+}
 
-    #[derive(Debug, thiserror::Error, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
-    #[error("a soft error")]
-    struct SoftError;
+#[macro_export]
+/// The `hard_bail` macro returns early with a hard error, `MalleableResult::Err(_)`.
+/// The error is converted with [Into] into the declared hard error type.
+macro_rules! hard_bail {
+    ($e:expr) => {
+        return ::core::result::Result::Err(::core::convert::Into::into($e))
+    };
+}
 
-    #[derive(Debug, thiserror::Error, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
-    #[error("a real dangerous error")]
-    struct HardError;
+#[macro_export]
+/// The `ensure_soft` macro returns early with a soft error if the condition is `false`, like [soft_bail].
+/// The error expression is only evaluated when the condition fails.
+macro_rules! ensure_soft {
+    ($cond:expr, $e:expr $(,)?) => {
+        if !$cond {
+            $crate::soft_bail!($e);
+        }
+    };
+}
 
-    #[instrument(err)]
-    fn tries_hard(
+#[macro_export]
+/// The `ensure_hard` macro returns early with a hard error if the condition is `false`, like [hard_bail].
+/// The error expression is only evaluated when the condition fails.
+macro_rules! ensure_hard {
+    ($cond:expr, $e:expr $(,)?) => {
+        if !$cond {
+            $crate::hard_bail!($e);
+        }
+    };
+}
+
+#[macro_export]
+/// The `try_soft_or` macro extracts the [SoftResult::Ok] value, or falls back to `default` on a [SoftResult::SoftErr].
+/// Unlike [try_soft], it never returns early, and `default` is only evaluated on a soft error.
+macro_rules! try_soft_or {
+    ($e:expr, $default:expr $(,)?) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(_) => $default,
+        }
+    };
+}
+
+#[macro_export]
+/// The `try_soft_else` macro extracts the [SoftResult::Ok] value, or runs the fallback block on a [SoftResult::SoftErr], like `let-else`.
+/// The block either evaluates to a replacement value or diverges, for example with `return`.
+/// The soft error can be bound with `try_soft_else!(expr, e => { .. })`.
+///
+/// ```rust
+/// use try_hard::{try_soft_else, MalleableResult, SoftResult};
+///
+/// fn lookup(id: u32) -> SoftResult<&'static str, String> {
+///     SoftResult::SoftErr(format!("no user {id}"))
+/// }
+///
+/// fn greet(id: u32) -> MalleableResult<String, String, ()> {
+///     let name = try_soft_else!(lookup(id), e => {
+///         if id == 0 {
+///             return Ok(SoftResult::SoftErr(e));
+///         }
+///         "guest"
+///     });
+///     Ok(SoftResult::Ok(format!("hello {name}")))
+/// }
+///
+/// assert_eq!(greet(1), Ok(SoftResult::Ok("hello guest".to_string())));
+/// assert_eq!(greet(0), Ok(SoftResult::SoftErr("no user 0".to_string())));
+/// ```
+macro_rules! try_soft_else {
+    ($e:expr, $err:ident => $fallback:block $(,)?) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr($err) => $fallback,
+        }
+    };
+    ($e:expr, $fallback:block $(,)?) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(_) => $fallback,
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard], but builds the short-circuit value with [Into] instead of returning a bare [MalleableResult].
+/// This allows early returns from functions returning a newtype wrapper around a [MalleableResult].
+///
+/// The return type of the function must implement `From<MalleableResult<T, S, H>>`, where `S` and `H` are the error types of the expression.
+/// `T` must be inferable from that [From] implementation, which is the case when the wrapper is generic over it.
+/// The errors themselves are not converted.
+///
+/// ```rust
+/// use try_hard::{try_hard_into, MalleableResult, SoftResult};
+///
+/// struct MyResult<T>(MalleableResult<T, String, String>);
+///
+/// impl<T> From<MalleableResult<T, String, String>> for MyResult<T> {
+///     fn from(result: MalleableResult<T, String, String>) -> Self {
+///         MyResult(result)
+///     }
+/// }
+///
+/// fn double(result: MalleableResult<i32, String, String>) -> MyResult<i32> {
+///     let x = try_hard_into!(result);
+///     MyResult(Ok(SoftResult::Ok(x * 2)))
+/// }
+///
+/// assert_eq!(double(Ok(SoftResult::Ok(2))).0, Ok(SoftResult::Ok(4)));
+/// assert_eq!(double(Err("boom".to_string())).0, Err("boom".to_string()));
+/// ```
+macro_rules! try_hard_into {
+    ($e:expr) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => t,
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                return ::core::convert::Into::into(::core::result::Result::Ok(
+                    $crate::SoftResult::SoftErr(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                return ::core::convert::Into::into(::core::result::Result::Err(e));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// The `soft_let` macro destructures a [SoftResult] like `let-else`: `soft_let!(Ok(x) = expr else { ... })`.
+/// The pattern inside the variant is bound on a match, otherwise the `else` block runs, and must diverge, like in `let-else`.
+/// Both variants are accepted, `soft_let!(SoftErr(e) = expr else { ... })` binds the soft error instead.
+///
+/// ```rust
+/// use try_hard::{soft_let, SoftResult};
+///
+/// fn describe(result: SoftResult<(i32, i32), &str>) -> String {
+///     soft_let!(Ok((x, y)) = result else {
+///         return "no point".to_string();
+///     });
+///     format!("({x}, {y})")
+/// }
+///
+/// assert_eq!(describe(SoftResult::Ok((1, 2))), "(1, 2)");
+/// assert_eq!(describe(SoftResult::SoftErr("missing")), "no point");
+/// ```
+macro_rules! soft_let {
+    ($variant:ident($p:pat) = $($rest:tt)+) => {
+        $crate::soft_let!(@munch $variant($p); [] $($rest)+)
+    };
+    (@munch $variant:ident($p:pat); [$($e:tt)+] else $fallback:block) => {
+        let $crate::SoftResult::$variant($p) = ($($e)+) else $fallback;
+    };
+    (@munch $variant:ident($p:pat); [$($e:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::soft_let!(@munch $variant($p); [$($e)* $next] $($rest)*)
+    };
+}
+
+#[macro_export]
+/// The `try_escalate` macro extracts the [SoftResult::Ok] value, or escalates the soft error into a hard error.
+/// On a [SoftResult::SoftErr], it returns early with `Err(Escalated(e))`, converted with [Into] into the declared hard error type.
+///
+/// ```rust
+/// use try_hard::{try_escalate, Escalated, MalleableResult, SoftResult};
+///
+/// fn must_exist(found: SoftResult<i32, &'static str>) -> MalleableResult<i32, (), Escalated<&'static str>> {
+///     let x = try_escalate!(found);
+///     Ok(SoftResult::Ok(x))
+/// }
+///
+/// assert_eq!(must_exist(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)));
+/// assert_eq!(must_exist(SoftResult::SoftErr("missing")), Err(Escalated("missing")));
+/// ```
+macro_rules! try_escalate {
+    ($e:expr $(,)?) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(e) => {
+                return ::core::result::Result::Err(::core::convert::Into::into(
+                    $crate::Escalated(e),
+                ));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tracing::instrument;
+
+    use super::*;
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+    #[error("a soft error")]
+    struct SoftError;
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+    #[error("a real dangerous error")]
+    struct HardError;
+
+    #[instrument(err)]
+    fn tries_hard(
         hard_result: MalleableResult<(), SoftError, HardError>,
         has_skipped: &mut bool,
     ) -> MalleableResult<(), SoftError, HardError> {
-        let x = try_hard!(hard_result);
+        try_hard!(hard_result);
         *has_skipped = false;
-        Ok(SoftResult::Ok(x))
+        Ok(SoftResult::Ok(()))
     }
 
     #[instrument(err)]
     fn tries_soft(
         soft_result: SoftResult<(), SoftError>,
     ) -> MalleableResult<(), SoftError, HardError> {
-        Ok(SoftResult::Ok(try_soft!(soft_result)))
+        try_soft!(soft_result);
+        Ok(SoftResult::Ok(()))
     }
 
     #[rstest]
@@ -164,4 +1353,1059 @@ mod tests {
         let result = tries_soft(soft_result.clone());
         assert_eq!(result, Ok(soft_result))
     }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(2))]
+    #[case(SoftResult::SoftErr(SoftError), SoftResult::SoftErr(SoftError))]
+    fn check_map(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: SoftResult<i32, SoftError>,
+    ) {
+        assert_eq!(soft_result.map(|x| x + 1), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr(SoftError), SoftResult::SoftErr("a soft error".to_string()))]
+    fn check_map_soft_err(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: SoftResult<i32, String>,
+    ) {
+        assert_eq!(soft_result.map_soft_err(|e| e.to_string()), expected);
+    }
+
+    fn non_empty(name: &str) -> SoftResult<&str, String> {
+        if name.is_empty() {
+            return SoftResult::SoftErr("empty".to_string());
+        }
+        SoftResult::Ok(name)
+    }
+
+    fn short(name: &str) -> SoftResult<&str, String> {
+        if name.len() > 5 {
+            return SoftResult::SoftErr("too long".to_string());
+        }
+        SoftResult::Ok(name)
+    }
+
+    #[rstest]
+    #[case("bob", SoftResult::Ok("bob"))]
+    #[case("", SoftResult::SoftErr("empty".to_string()))]
+    #[case("alexander", SoftResult::SoftErr("too long".to_string()))]
+    fn check_and_then(#[case] name: &str, #[case] expected: SoftResult<&str, String>) {
+        assert_eq!(non_empty(name).and_then(short), expected);
+    }
+
+    #[test]
+    fn check_and_then_short_circuits() {
+        let mut called = false;
+        let result = non_empty("").and_then(|name| {
+            called = true;
+            short(name)
+        });
+        assert_eq!(result, SoftResult::SoftErr("empty".to_string()));
+        assert!(!called);
+    }
+
+    #[rstest]
+    #[case("bob", SoftResult::Ok("bob"))]
+    #[case("", SoftResult::Ok("anonymous"))]
+    #[case("alexander", SoftResult::SoftErr(SoftError))]
+    fn check_or_else(#[case] name: &str, #[case] expected: SoftResult<&str, SoftError>) {
+        let result = non_empty(name)
+            .and_then(short)
+            .or_else(|e| match e.as_str() {
+                "empty" => SoftResult::Ok("anonymous"),
+                _ => SoftResult::SoftErr(SoftError),
+            });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn check_unwrap() {
+        assert_eq!(SoftResult::<i32, SoftError>::Ok(1).unwrap(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "called SoftResult::unwrap on a SoftErr value: SoftError")]
+    fn check_unwrap_panics() {
+        SoftResult::<i32, SoftError>::SoftErr(SoftError).unwrap();
+    }
+
+    #[test]
+    fn check_expect() {
+        assert_eq!(
+            SoftResult::<i32, SoftError>::Ok(1).expect("no soft error"),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no soft error: SoftError")]
+    fn check_expect_panics() {
+        SoftResult::<i32, SoftError>::SoftErr(SoftError).expect("no soft error");
+    }
+
+    #[test]
+    fn check_unwrap_soft_err() {
+        assert_eq!(
+            SoftResult::<i32, SoftError>::SoftErr(SoftError).unwrap_soft_err(),
+            SoftError
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "called SoftResult::unwrap_soft_err on an Ok value: 1")]
+    fn check_unwrap_soft_err_panics() {
+        SoftResult::<i32, SoftError>::Ok(1).unwrap_soft_err();
+    }
+
+    #[test]
+    fn check_expect_soft_err() {
+        assert_eq!(
+            SoftResult::<i32, SoftError>::SoftErr(SoftError).expect_soft_err("should be missing"),
+            SoftError
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "should be missing: [1, 2]")]
+    fn check_expect_soft_err_panics() {
+        SoftResult::<Vec<i32>, SoftError>::Ok(vec![1, 2]).expect_soft_err("should be missing");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct NotFound;
+
+    fn find_items(found: bool) -> SoftResult<Vec<i32>, NotFound> {
+        if found {
+            SoftResult::Ok(vec![1, 2, 3])
+        } else {
+            SoftResult::SoftErr(NotFound)
+        }
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), 1)]
+    #[case(SoftResult::SoftErr(SoftError), 0)]
+    fn check_unwrap_or(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: i32) {
+        assert_eq!(soft_result.clone().unwrap_or(0), expected);
+        assert_eq!(soft_result.unwrap_or_else(|_| 0), expected);
+    }
+
+    #[rstest]
+    #[case(true, vec![1, 2, 3])]
+    #[case(false, vec![])]
+    fn check_unwrap_or_default(#[case] found: bool, #[case] expected: Vec<i32>) {
+        assert_eq!(find_items(found).unwrap_or_default(), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), true)]
+    #[case(SoftResult::SoftErr(SoftError), false)]
+    fn check_is_ok(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: bool) {
+        assert_eq!(soft_result.is_ok(), expected);
+        assert_eq!(soft_result.is_soft_err(), !expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), true)]
+    #[case(SoftResult::Ok(0), false)]
+    #[case(SoftResult::SoftErr(SoftError), false)]
+    fn check_is_ok_and(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: bool) {
+        assert_eq!(soft_result.is_ok_and(|x| *x > 0), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), false)]
+    #[case(SoftResult::SoftErr(SoftError), true)]
+    fn check_is_soft_err_and(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            soft_result.is_soft_err_and(|e| e.to_string().contains("soft")),
+            expected
+        );
+        assert!(!soft_result.is_soft_err_and(|e| e.to_string().contains("dangerous")));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Some(1), None)]
+    #[case(SoftResult::SoftErr(SoftError), None, Some(SoftError))]
+    fn check_ok_and_soft_err(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected_ok: Option<i32>,
+        #[case] expected_soft_err: Option<SoftError>,
+    ) {
+        assert_eq!(soft_result.clone().ok(), expected_ok);
+        assert_eq!(soft_result.clone().soft_err(), expected_soft_err);
+
+        let round_trip = match soft_result.clone().ok() {
+            Some(t) => SoftResult::Ok(t),
+            None => SoftResult::SoftErr(soft_result.clone().soft_err().unwrap()),
+        };
+        assert_eq!(round_trip, soft_result);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(2))]
+    #[case(SoftResult::SoftErr(SoftError), SoftResult::SoftErr(SoftError))]
+    fn check_as_mut(
+        #[case] mut soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: SoftResult<i32, SoftError>,
+    ) {
+        let _ = soft_result.as_mut().map(|x| *x += 1);
+        assert_eq!(soft_result, expected);
+    }
+
+    #[test]
+    fn check_as_ref() {
+        let soft_result = SoftResult::<String, SoftError>::Ok("hello".to_string());
+        assert_eq!(soft_result.as_ref().map(|s| s.len()), SoftResult::Ok(5));
+        assert_eq!(soft_result, SoftResult::Ok("hello".to_string()));
+
+        let soft_result = SoftResult::<String, SoftError>::SoftErr(SoftError);
+        assert_eq!(soft_result.as_ref(), SoftResult::SoftErr(&SoftError));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), vec![1])]
+    #[case(SoftResult::SoftErr(SoftError), vec![])]
+    fn check_iter(#[case] mut soft_result: SoftResult<i32, SoftError>, #[case] expected: Vec<i32>) {
+        assert_eq!(soft_result.iter().copied().collect::<Vec<_>>(), expected);
+
+        for x in soft_result.iter_mut() {
+            *x += 1;
+        }
+        for x in &mut soft_result {
+            *x -= 1;
+        }
+        assert_eq!((&soft_result).into_iter().count(), expected.len());
+        assert_eq!(soft_result.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn check_flatten_soft_results() {
+        let results = vec![
+            SoftResult::Ok(1),
+            SoftResult::SoftErr(SoftError),
+            SoftResult::Ok(3),
+            SoftResult::SoftErr(SoftError),
+        ];
+        assert_eq!(
+            results.into_iter().flatten().collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[rstest]
+    #[case(Ok(1), SoftResult::Ok(1))]
+    #[case(Err(SoftError), SoftResult::SoftErr(SoftError))]
+    fn check_result_conversions(
+        #[case] result: Result<i32, SoftError>,
+        #[case] soft_result: SoftResult<i32, SoftError>,
+    ) {
+        assert_eq!(SoftResult::from(result.clone()), soft_result);
+        assert_eq!(soft_result.into_result(), result);
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(SoftResult::Ok(1), r#"{"Ok":1}"#)]
+    #[case(SoftResult::SoftErr("not found".to_string()), r#"{"SoftErr":"not found"}"#)]
+    fn check_serde_round_trip(#[case] soft_result: SoftResult<i32, String>, #[case] json: &str) {
+        assert_eq!(serde_json::to_string(&soft_result).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<SoftResult<i32, String>>(json).unwrap(),
+            soft_result
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn check_serde_rejects_unknown_tags() {
+        assert!(serde_json::from_str::<SoftResult<i32, String>>(r#"{"Err":"oops"}"#).is_err());
+    }
+
+    #[test]
+    fn check_collect() {
+        let results = vec![SoftResult::<_, SoftError>::Ok(1), SoftResult::Ok(2)];
+        assert_eq!(
+            results.into_iter().collect::<SoftResult<Vec<_>, _>>(),
+            SoftResult::Ok(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn check_collect_stops_at_first_soft_err() {
+        let results = (0..).map(|i| match i {
+            0 | 1 => SoftResult::Ok(i),
+            2 => SoftResult::SoftErr(i),
+            _ => panic!("polled past the first soft error"),
+        });
+        assert_eq!(
+            results.collect::<SoftResult<Vec<_>, _>>(),
+            SoftResult::SoftErr(2)
+        );
+    }
+
+    fn load_config(required: bool) -> MalleableResult<i32, SoftError, HardError> {
+        let config = SoftResult::<i32, _>::SoftErr(SoftError);
+        if required {
+            let config = config.harden(|_| HardError)?;
+            return Ok(SoftResult::Ok(config));
+        }
+        Ok(config)
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Ok(1))]
+    #[case(SoftResult::SoftErr(SoftError), Err(HardError))]
+    fn check_harden(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: Result<i32, HardError>,
+    ) {
+        assert_eq!(soft_result.harden(|_| HardError), expected);
+    }
+
+    #[rstest]
+    #[case(false, Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(true, Err(HardError))]
+    fn check_harden_escalates(
+        #[case] required: bool,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+    ) {
+        assert_eq!(load_config(required), expected);
+    }
+
+    fn bails(input: i32) -> MalleableResult<i32, String, HardError> {
+        if input < 0 {
+            soft_bail!("negative input");
+        }
+        if input == 0 {
+            hard_bail!(HardError);
+        }
+        Ok(SoftResult::Ok(input))
+    }
+
+    #[rstest]
+    #[case(1, Ok(SoftResult::Ok(1)))]
+    #[case(-1, Ok(SoftResult::SoftErr("negative input".to_string())))]
+    #[case(0, Err(HardError))]
+    fn check_bail(#[case] input: i32, #[case] expected: MalleableResult<i32, String, HardError>) {
+        assert_eq!(bails(input), expected);
+    }
+
+    fn ensures(
+        input: i32,
+        evaluated: &mut Vec<&'static str>,
+    ) -> MalleableResult<i32, String, HardError> {
+        let mut soft = |name| {
+            evaluated.push(name);
+            name
+        };
+        ensure_soft!(input >= 0, soft("negative input"));
+        ensure_hard!(input != 0, HardError);
+        Ok(SoftResult::Ok(input))
+    }
+
+    #[rstest]
+    #[case(1, Ok(SoftResult::Ok(1)), vec![])]
+    #[case(-1, Ok(SoftResult::SoftErr("negative input".to_string())), vec!["negative input"])]
+    #[case(0, Err(HardError), vec![])]
+    fn check_ensure(
+        #[case] input: i32,
+        #[case] expected: MalleableResult<i32, String, HardError>,
+        #[case] expected_evaluated: Vec<&'static str>,
+    ) {
+        let mut evaluated = vec![];
+        assert_eq!(ensures(input, &mut evaluated), expected);
+        assert_eq!(evaluated, expected_evaluated);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), "<p>1</p>", "<p>1</p>")]
+    #[case(SoftResult::SoftErr(SoftError), "<p>error</p>", "<p>a soft error</p>")]
+    fn check_map_or(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: &str,
+        #[case] expected_else: &str,
+    ) {
+        assert_eq!(
+            soft_result
+                .clone()
+                .map_or("<p>error</p>".to_string(), |x| format!("<p>{x}</p>")),
+            expected
+        );
+        assert_eq!(
+            soft_result.map_or_else(|e| format!("<p>{e}</p>"), |x| format!("<p>{x}</p>")),
+            expected_else
+        );
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), 1, 0)]
+    #[case(SoftResult::SoftErr(SoftError), 0, 1)]
+    fn check_inspect(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected_oks: i32,
+        #[case] expected_soft_errs: i32,
+    ) {
+        let mut oks = 0;
+        let mut soft_errs = 0;
+        let result = soft_result
+            .clone()
+            .inspect(|_| oks += 1)
+            .inspect_soft_err(|_| soft_errs += 1)
+            .map(|x| x + 1);
+        assert_eq!(result, soft_result.map(|x| x + 1));
+        assert_eq!(oks, expected_oks);
+        assert_eq!(soft_errs, expected_soft_errs);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct UserNotFound;
+
+    impl SoftStatus for UserNotFound {
+        fn status_code(&self) -> u16 {
+            404
+        }
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr(UserNotFound), SoftResult::SoftErr((404, UserNotFound)))]
+    fn check_with_status(
+        #[case] soft_result: SoftResult<i32, UserNotFound>,
+        #[case] expected: SoftResult<i32, (u16, UserNotFound)>,
+    ) {
+        assert_eq!(soft_result.with_status(), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(Some(1)), Some(SoftResult::Ok(1)))]
+    #[case(SoftResult::Ok(None), None)]
+    #[case(SoftResult::SoftErr(SoftError), Some(SoftResult::SoftErr(SoftError)))]
+    fn check_transpose(
+        #[case] soft_result: SoftResult<Option<i32>, SoftError>,
+        #[case] expected: Option<SoftResult<i32, SoftError>>,
+    ) {
+        assert_eq!(soft_result.transpose(), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(Some(1)), SoftResult::Ok(1))]
+    #[case(SoftResult::Ok(None), SoftResult::SoftErr("not found"))]
+    #[case(SoftResult::SoftErr("earlier"), SoftResult::SoftErr("earlier"))]
+    fn check_ok_some_or(
+        #[case] soft_result: SoftResult<Option<i32>, &str>,
+        #[case] expected: SoftResult<i32, &str>,
+    ) {
+        assert_eq!(soft_result.ok_some_or("not found"), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(SoftResult::Ok(1)), SoftResult::Ok(1))]
+    #[case(
+        SoftResult::Ok(SoftResult::SoftErr("inner")),
+        SoftResult::SoftErr("inner")
+    )]
+    #[case(SoftResult::SoftErr("outer"), SoftResult::SoftErr("outer"))]
+    fn check_flatten(
+        #[case] soft_result: SoftResult<SoftResult<i32, &str>, &str>,
+        #[case] expected: SoftResult<i32, &str>,
+    ) {
+        assert_eq!(soft_result.flatten(), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), true, false)]
+    #[case(SoftResult::Ok(2), false, false)]
+    #[case(SoftResult::SoftErr("not found".to_string()), false, true)]
+    #[case(SoftResult::SoftErr("forbidden".to_string()), false, false)]
+    fn check_contains(
+        #[case] soft_result: SoftResult<i32, String>,
+        #[case] contains_one: bool,
+        #[case] contains_not_found: bool,
+    ) {
+        assert_eq!(soft_result.contains(&1), contains_one);
+        assert_eq!(
+            soft_result.contains_soft_err(&"not found"),
+            contains_not_found
+        );
+    }
+
+    #[test]
+    fn check_default() {
+        assert_eq!(
+            SoftResult::<Vec<i32>, SoftError>::default(),
+            SoftResult::Ok(vec![])
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Response {
+        Ok(String),
+        NotFound(String),
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Response::Ok("1".to_string()))]
+    #[case(SoftResult::SoftErr(SoftError), Response::NotFound("a soft error".to_string()))]
+    fn check_fold(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: Response) {
+        let response = soft_result.fold(
+            |x| Response::Ok(x.to_string()),
+            |e| Response::NotFound(e.to_string()),
+        );
+        assert_eq!(response, expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok("hello".to_string()))]
+    #[case(SoftResult::SoftErr(SoftError))]
+    fn check_cloned(#[case] soft_result: SoftResult<String, SoftError>) {
+        assert_eq!(soft_result.as_ref().cloned(), soft_result);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr(404))]
+    fn check_copied(#[case] soft_result: SoftResult<i32, u16>) {
+        assert_eq!(soft_result.as_ref().copied(), soft_result);
+    }
+
+    fn tries_soft_or(
+        soft_result: SoftResult<i32, SoftError>,
+        continued: &mut bool,
+    ) -> MalleableResult<i32, SoftError, HardError> {
+        let x = try_soft_or!(soft_result, 0);
+        *continued = true;
+        Ok(SoftResult::Ok(x + 1))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), 2)]
+    #[case(SoftResult::SoftErr(SoftError), 1)]
+    fn check_try_soft_or(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: i32) {
+        let mut continued = false;
+        assert_eq!(
+            tries_soft_or(soft_result, &mut continued),
+            Ok(SoftResult::Ok(expected))
+        );
+        assert!(continued);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), "ok")]
+    #[case(SoftResult::SoftErr(SoftError), "soft error: a soft error")]
+    fn check_display(#[case] soft_result: SoftResult<i32, SoftError>, #[case] expected: &str) {
+        assert_eq!(soft_result.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(20), SoftResult::Ok(20))]
+    #[case(SoftResult::Ok(12), SoftResult::SoftErr("too young".to_string()))]
+    #[case(SoftResult::SoftErr("missing age".to_string()), SoftResult::SoftErr("missing age".to_string()))]
+    fn check_filter(
+        #[case] soft_result: SoftResult<i32, String>,
+        #[case] expected: SoftResult<i32, String>,
+    ) {
+        assert_eq!(
+            soft_result
+                .clone()
+                .filter("too young".to_string(), |age| *age >= 18),
+            expected
+        );
+        assert_eq!(
+            soft_result.filter_with(|age| (*age < 18).then(|| "too young".to_string())),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok("a"), SoftResult::Ok((1, "a")))]
+    #[case(
+        SoftResult::Ok(1),
+        SoftResult::SoftErr("second"),
+        SoftResult::SoftErr("second")
+    )]
+    #[case(
+        SoftResult::SoftErr("first"),
+        SoftResult::Ok("a"),
+        SoftResult::SoftErr("first")
+    )]
+    #[case(
+        SoftResult::SoftErr("first"),
+        SoftResult::SoftErr("second"),
+        SoftResult::SoftErr("first")
+    )]
+    fn check_zip(
+        #[case] first: SoftResult<i32, &str>,
+        #[case] second: SoftResult<&str, &str>,
+        #[case] expected: SoftResult<(i32, &str), &str>,
+    ) {
+        assert_eq!(first.zip(second), expected);
+    }
+
+    #[rstest]
+    #[case(Some(1), SoftResult::Ok(1))]
+    #[case(None, SoftResult::SoftErr("not found"))]
+    fn check_from_option(#[case] opt: Option<i32>, #[case] expected: SoftResult<i32, &str>) {
+        assert_eq!(SoftResult::from_option(opt, "not found"), expected);
+        assert_eq!(SoftResult::from_option_with(opt, || "not found"), expected);
+    }
+
+    #[test]
+    fn check_from_option_with_is_lazy() {
+        let soft_result: SoftResult<i32, &str> =
+            SoftResult::from_option_with(Some(1), || panic!("should not be called"));
+        assert_eq!(soft_result, SoftResult::Ok(1));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), 1)]
+    #[case(SoftResult::SoftErr("cache miss"), 42)]
+    fn check_get_or_insert_with(
+        #[case] mut soft_result: SoftResult<i32, &str>,
+        #[case] expected: i32,
+    ) {
+        let value = soft_result.get_or_insert_with(|| 42);
+        assert_eq!(*value, expected);
+        *value += 1;
+        assert_eq!(soft_result, SoftResult::Ok(expected + 1));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(5), Ok(5), true)]
+    #[case(SoftResult::Ok(5), Ok(6), false)]
+    #[case(SoftResult::Ok(5), Err("not found"), false)]
+    #[case(SoftResult::SoftErr("not found"), Err("not found"), true)]
+    #[case(SoftResult::SoftErr("not found"), Err("gone"), false)]
+    #[case(SoftResult::SoftErr("not found"), Ok(5), false)]
+    fn check_eq_result(
+        #[case] soft_result: SoftResult<i32, &str>,
+        #[case] result: Result<i32, &str>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(soft_result == result, expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr(SoftError))]
+    fn check_into_malleable(#[case] soft_result: SoftResult<i32, SoftError>) {
+        let malleable: MalleableResult<i32, SoftError, HardError> =
+            soft_result.clone().into_malleable();
+        assert_eq!(malleable, Ok(soft_result));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr("ignored"))]
+    #[deny(unused_must_use)]
+    fn check_discard(#[case] soft_result: SoftResult<i32, &str>) {
+        soft_result.discard();
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(2), SoftResult::Ok("2".to_string()))]
+    #[case(SoftResult::SoftErr("not found"), SoftResult::SoftErr(9))]
+    fn check_map_both(
+        #[case] soft_result: SoftResult<i32, &str>,
+        #[case] expected: SoftResult<String, usize>,
+    ) {
+        assert_eq!(soft_result.map_both(|t| t.to_string(), str::len), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr("not found"))]
+    fn check_result_round_trip(#[case] soft_result: SoftResult<i32, &str>) {
+        let result: Result<i32, &str> = soft_result.clone().into();
+        assert_eq!(soft_result, result);
+        let round_trip: SoftResult<i32, &str> = result.into();
+        assert_eq!(round_trip, soft_result);
+    }
+
+    fn not_empty(name: &str) -> SoftResult<(), String> {
+        non_empty(name).map(|_| ())
+    }
+
+    fn not_long(name: &str) -> SoftResult<(), String> {
+        short(name).map(|_| ())
+    }
+
+    fn merge(first: String, second: String) -> String {
+        format!("{first}; {second}")
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(()), "bob", SoftResult::Ok(()))]
+    #[case(SoftResult::Ok(()), "alexander", SoftResult::SoftErr("too long".to_string()))]
+    #[case(SoftResult::SoftErr("empty".to_string()), "bob", SoftResult::SoftErr("empty".to_string()))]
+    #[case(
+        SoftResult::SoftErr("empty".to_string()),
+        "alexander",
+        SoftResult::SoftErr("empty; too long".to_string())
+    )]
+    fn check_accumulate_check(
+        #[case] soft_result: SoftResult<(), String>,
+        #[case] name: &str,
+        #[case] expected: SoftResult<(), String>,
+    ) {
+        assert_eq!(
+            soft_result.accumulate_check(name, not_long, merge),
+            expected
+        );
+    }
+
+    #[test]
+    fn check_accumulate_check_chain_merges_two_messages() {
+        let name = "";
+        let validated = SoftResult::Ok(name)
+            .accumulate_check(name, not_empty, merge)
+            .accumulate_check(
+                name,
+                |n| {
+                    if n.starts_with('A') {
+                        SoftResult::Ok(())
+                    } else {
+                        SoftResult::SoftErr("not capitalized".to_string())
+                    }
+                },
+                merge,
+            );
+        assert_eq!(
+            validated,
+            SoftResult::SoftErr("empty; not capitalized".to_string())
+        );
+    }
+
+    // Only the correct variant is tested: calling these methods on the wrong variant is undefined behavior,
+    // so no assertion could observe it reliably.
+    #[cfg(debug_assertions)]
+    #[test]
+    fn check_unwrap_unchecked() {
+        let ok: SoftResult<i32, &str> = SoftResult::Ok(1);
+        let soft_err: SoftResult<i32, &str> = SoftResult::SoftErr("soft");
+        // SAFETY: each result is unwrapped on its own variant.
+        unsafe {
+            assert_eq!(ok.unwrap_unchecked(), 1);
+            assert_eq!(soft_err.unwrap_soft_err_unchecked(), "soft");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(1))]
+    #[case(
+        SoftResult::SoftErr(SoftError),
+        SoftResult::SoftErr("while loading user 7: a soft error".to_string())
+    )]
+    fn check_with_soft_context(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: SoftResult<i32, String>,
+    ) {
+        assert_eq!(
+            soft_result.with_soft_context(format_args!("while loading user {}", 7)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(find_items(true), SoftResult::Ok(vec![1, 2, 3]))]
+    #[case(find_items(false), SoftResult::Ok(vec![]))]
+    fn check_or_default_soft(
+        #[case] soft_result: SoftResult<Vec<i32>, NotFound>,
+        #[case] expected: SoftResult<Vec<i32>, NotFound>,
+    ) {
+        assert_eq!(soft_result.or_default_soft(), expected);
+    }
+
+    #[test]
+    fn check_iter_soft_err() {
+        let results = [
+            SoftResult::Ok(1),
+            SoftResult::SoftErr("empty name"),
+            SoftResult::Ok(2),
+            SoftResult::SoftErr("invalid email"),
+        ];
+        let soft_errs: Vec<_> = results.iter().flat_map(|r| r.iter_soft_err()).collect();
+        assert_eq!(soft_errs, vec![&"empty name", &"invalid email"]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[rstest]
+    #[case(SoftResult::Ok(1), Some(1), None)]
+    #[case(SoftResult::SoftErr("soft"), None, Some("soft"))]
+    fn check_tap_dbg(
+        #[case] soft_result: SoftResult<i32, &str>,
+        #[case] expected_ok: Option<i32>,
+        #[case] expected_soft_err: Option<&str>,
+    ) {
+        let mut tapped_ok = None;
+        let mut tapped_soft_err = None;
+        let tapped = soft_result
+            .clone()
+            .tap_dbg(|t| tapped_ok = Some(*t))
+            .tap_soft_dbg(|e| tapped_soft_err = Some(*e));
+        assert_eq!(tapped, soft_result);
+        assert_eq!(tapped_ok, expected_ok);
+        assert_eq!(tapped_soft_err, expected_soft_err);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum AppSoftError {
+        Soft(SoftError),
+    }
+
+    impl From<SoftError> for AppSoftError {
+        fn from(e: SoftError) -> Self {
+            AppSoftError::Soft(e)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum AppHardError {
+        Hard(HardError),
+    }
+
+    impl From<HardError> for AppHardError {
+        fn from(e: HardError) -> Self {
+            AppHardError::Hard(e)
+        }
+    }
+
+    fn tries_soft_into(
+        soft_result: SoftResult<i32, SoftError>,
+    ) -> MalleableResult<i32, AppSoftError, AppHardError> {
+        let x = try_soft!(soft_result);
+        Ok(SoftResult::Ok(x))
+    }
+
+    fn tries_hard_into(
+        hard_result: MalleableResult<i32, SoftError, HardError>,
+    ) -> MalleableResult<i32, AppSoftError, AppHardError> {
+        let x = try_hard!(hard_result);
+        Ok(SoftResult::Ok(x))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Ok(SoftResult::SoftErr(AppSoftError::Soft(SoftError)))
+    )]
+    #[case(Err(HardError), Err(AppHardError::Hard(HardError)))]
+    fn check_try_converts_errors(
+        #[case] hard_result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, AppSoftError, AppHardError>,
+    ) {
+        if let Ok(soft_result) = hard_result.clone() {
+            assert_eq!(tries_soft_into(soft_result), expected);
+        }
+        assert_eq!(tries_hard_into(hard_result), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr("soft".to_string()))]
+    fn check_replace(#[case] soft_result: SoftResult<i32, String>) {
+        let mut current = soft_result.clone();
+        assert_eq!(current.replace(2), soft_result);
+        assert_eq!(current, SoftResult::Ok(2));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr("soft".to_string()))]
+    fn check_take(#[case] soft_result: SoftResult<i32, String>) {
+        let mut current = soft_result.clone();
+        assert_eq!(current.take(), soft_result);
+        assert_eq!(current, SoftResult::SoftErr(String::new()));
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok("two"), SoftResult::Ok("two"))]
+    #[case(
+        SoftResult::Ok(1),
+        SoftResult::SoftErr("late"),
+        SoftResult::SoftErr("late")
+    )]
+    #[case(
+        SoftResult::SoftErr("early"),
+        SoftResult::Ok("two"),
+        SoftResult::SoftErr("early")
+    )]
+    #[case(
+        SoftResult::SoftErr("early"),
+        SoftResult::SoftErr("late"),
+        SoftResult::SoftErr("early")
+    )]
+    fn check_and(
+        #[case] first: SoftResult<i32, &str>,
+        #[case] second: SoftResult<&str, &str>,
+        #[case] expected: SoftResult<&str, &str>,
+    ) {
+        assert_eq!(first.and(second), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(2), SoftResult::Ok(1))]
+    #[case(SoftResult::Ok(1), SoftResult::SoftErr(404), SoftResult::Ok(1))]
+    #[case(SoftResult::SoftErr("early"), SoftResult::Ok(2), SoftResult::Ok(2))]
+    #[case(
+        SoftResult::SoftErr("early"),
+        SoftResult::SoftErr(404),
+        SoftResult::SoftErr(404)
+    )]
+    fn check_or(
+        #[case] first: SoftResult<i32, &str>,
+        #[case] second: SoftResult<i32, u16>,
+        #[case] expected: SoftResult<i32, u16>,
+    ) {
+        assert_eq!(first.or(second), expected);
+    }
+
+    #[test]
+    fn check_const() {
+        const OK: SoftResult<i32, &str> = SoftResult::ok_const(1);
+        const SOFT_ERR: SoftResult<i32, &str> = SoftResult::soft_const("soft");
+        const _: () = assert!(OK.is_ok() && !OK.is_soft_err());
+        const _: () = assert!(SOFT_ERR.is_soft_err() && !SOFT_ERR.is_ok());
+        assert_eq!(OK, SoftResult::Ok(1));
+        assert_eq!(SOFT_ERR, SoftResult::SoftErr("soft"));
+    }
+
+    fn tries_soft_else(
+        soft_result: SoftResult<i32, &'static str>,
+    ) -> MalleableResult<i32, &'static str, ()> {
+        let x = try_soft_else!(soft_result, e => {
+            if e == "fatal" {
+                return Ok(SoftResult::SoftErr(e));
+            }
+            0
+        });
+        let y = try_soft_else!(SoftResult::<i32, ()>::SoftErr(()), { 10 });
+        Ok(SoftResult::Ok(x + y))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Ok(SoftResult::Ok(11)))]
+    #[case(SoftResult::SoftErr("missing"), Ok(SoftResult::Ok(10)))]
+    #[case(SoftResult::SoftErr("fatal"), Ok(SoftResult::SoftErr("fatal")))]
+    fn check_try_soft_else(
+        #[case] soft_result: SoftResult<i32, &'static str>,
+        #[case] expected: MalleableResult<i32, &'static str, ()>,
+    ) {
+        assert_eq!(tries_soft_else(soft_result), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok((1, "a")), (SoftResult::Ok(1), SoftResult::Ok("a")))]
+    #[case(SoftResult::SoftErr("soft"), (SoftResult::SoftErr("soft"), SoftResult::SoftErr("soft")))]
+    fn check_unzip(
+        #[case] soft_result: SoftResult<(i32, &str), &str>,
+        #[case] expected: (SoftResult<i32, &str>, SoftResult<&str, &str>),
+    ) {
+        assert_eq!(soft_result.unzip(), expected);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MyResult<T>(MalleableResult<T, SoftError, HardError>);
+
+    impl<T> From<MalleableResult<T, SoftError, HardError>> for MyResult<T> {
+        fn from(result: MalleableResult<T, SoftError, HardError>) -> Self {
+            MyResult(result)
+        }
+    }
+
+    fn tries_hard_into_wrapper(
+        hard_result: MalleableResult<i32, SoftError, HardError>,
+    ) -> MyResult<i32> {
+        let x = try_hard_into!(hard_result);
+        MyResult(Ok(SoftResult::Ok(x + 1)))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(2)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(Err(HardError), Err(HardError))]
+    fn check_try_hard_into(
+        #[case] hard_result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+    ) {
+        assert_eq!(tries_hard_into_wrapper(hard_result), MyResult(expected));
+    }
+
+    #[test]
+    fn check_map_soft_err_in_place() {
+        let mut soft_result: SoftResult<i32, String> =
+            SoftResult::SoftErr(String::with_capacity(32));
+        let buffer = soft_result.as_ref().soft_err().map(|e| e.as_ptr());
+        soft_result.map_soft_err_in_place(|e| e.push_str("invalid"));
+        assert_eq!(soft_result.as_ref().soft_err().map(|e| e.as_ptr()), buffer);
+        assert_eq!(soft_result, SoftResult::SoftErr("invalid".to_string()));
+
+        let mut soft_result: SoftResult<i32, String> = SoftResult::Ok(1);
+        soft_result.map_soft_err_in_place(|_| unreachable!());
+        assert_eq!(soft_result, SoftResult::Ok(1));
+    }
+
+    fn soft_lets(
+        soft_result: SoftResult<i32, &'static str>,
+    ) -> MalleableResult<i32, &'static str, ()> {
+        soft_let!(Ok(x) = soft_result else {
+            return Ok(SoftResult::SoftErr("early return"));
+        });
+        Ok(SoftResult::Ok(x + 1))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Ok(SoftResult::Ok(2)))]
+    #[case(
+        SoftResult::SoftErr("missing"),
+        Ok(SoftResult::SoftErr("early return"))
+    )]
+    fn check_soft_let(
+        #[case] soft_result: SoftResult<i32, &'static str>,
+        #[case] expected: MalleableResult<i32, &'static str, ()>,
+    ) {
+        assert_eq!(soft_lets(soft_result), expected);
+    }
+
+    #[test]
+    fn check_soft_let_soft_err() {
+        let mut fallbacks = 0;
+        for soft_result in [SoftResult::Ok(1), SoftResult::SoftErr("missing")] {
+            soft_let!(SoftErr(e) = if true { soft_result } else { SoftResult::Ok(0) } else {
+                fallbacks += 1;
+                continue;
+            });
+            assert_eq!(e, "missing");
+        }
+        assert_eq!(fallbacks, 1);
+    }
+
+    fn tries_escalate(
+        soft_result: SoftResult<i32, SoftError>,
+    ) -> MalleableResult<i32, (), Escalated<SoftError>> {
+        let x = try_escalate!(soft_result);
+        Ok(SoftResult::Ok(x))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Ok(SoftResult::Ok(1)))]
+    #[case(SoftResult::SoftErr(SoftError), Err(Escalated(SoftError)))]
+    fn check_try_escalate(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: MalleableResult<i32, (), Escalated<SoftError>>,
+    ) {
+        assert_eq!(
+            soft_result.clone().escalate(),
+            soft_result.clone().into_result().map_err(Escalated)
+        );
+        assert_eq!(tries_escalate(soft_result), expected);
+    }
 }
@@ -0,0 +1,82 @@
+//! Named constructors for [MalleableResult], instead of nesting `Ok(SoftResult::Ok(_))` by hand.
+
+use crate::{MalleableResult, SoftResult};
+
+/// Builds an ok [MalleableResult], `Ok(SoftResult::Ok(t))`.
+///
+/// ```rust
+/// use try_hard::{make, MalleableResult, SoftResult};
+///
+/// let result: MalleableResult<i32, (), ()> = make::ok(1);
+/// assert_eq!(result, Ok(SoftResult::Ok(1)));
+/// ```
+#[inline]
+pub fn ok<T, S, H>(t: T) -> MalleableResult<T, S, H> {
+    Ok(SoftResult::Ok(t))
+}
+
+/// Builds a soft error [MalleableResult], `Ok(SoftResult::SoftErr(s))`.
+///
+/// ```rust
+/// use try_hard::{make, MalleableResult, SoftResult};
+///
+/// let result: MalleableResult<i32, &str, ()> = make::soft("not found");
+/// assert_eq!(result, Ok(SoftResult::SoftErr("not found")));
+/// ```
+#[inline]
+pub fn soft<T, S, H>(s: S) -> MalleableResult<T, S, H> {
+    Ok(SoftResult::SoftErr(s))
+}
+
+/// Builds a hard error [MalleableResult], `Err(h)`.
+///
+/// ```rust
+/// use try_hard::{make, MalleableResult};
+///
+/// let result: MalleableResult<i32, (), &str> = make::hard("database failure");
+/// assert_eq!(result, Err("database failure"));
+/// ```
+#[inline]
+pub fn hard<T, S, H>(h: H) -> MalleableResult<T, S, H> {
+    Err(h)
+}
+
+/// Classifies the error of a core [Result] as soft or hard, building a [MalleableResult].
+/// Errors matching `is_soft` are converted with `to_soft`, the others with `to_hard`.
+/// This is the main entry point for existing code that returns a [Result].
+///
+/// ```rust
+/// use try_hard::{make, MalleableResult, SoftResult};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum DbError {
+///     NotFound,
+///     ConnectionLost,
+/// }
+///
+/// fn classify(result: Result<i32, DbError>) -> MalleableResult<i32, String, DbError> {
+///     make::classify(result, |e| *e == DbError::NotFound, |_| "not found".to_string(), |e| e)
+/// }
+///
+/// assert_eq!(classify(Ok(1)), Ok(SoftResult::Ok(1)));
+/// assert_eq!(classify(Err(DbError::NotFound)), Ok(SoftResult::SoftErr("not found".to_string())));
+/// assert_eq!(classify(Err(DbError::ConnectionLost)), Err(DbError::ConnectionLost));
+/// ```
+#[inline]
+pub fn classify<T, E, S, H, P, FS, FH>(
+    result: Result<T, E>,
+    is_soft: P,
+    to_soft: FS,
+    to_hard: FH,
+) -> MalleableResult<T, S, H>
+where
+    P: FnOnce(&E) -> bool,
+    FS: FnOnce(E) -> S,
+    FH: FnOnce(E) -> H,
+{
+    match result {
+        Ok(t) => Ok(SoftResult::Ok(t)),
+        Err(e) if is_soft(&e) => Ok(SoftResult::SoftErr(to_soft(e))),
+        Err(e) => Err(to_hard(e)),
+    }
+}
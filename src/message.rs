@@ -0,0 +1,177 @@
+//! Formatting of soft errors into messages, without allocating when the message is already a string.
+//! Requires the `alloc` feature.
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+use crate::{Escalated, SoftErrors, SoftResult};
+
+/// Formats a soft error into a message, borrowing it when possible.
+///
+/// A [Display] bound cannot tell whether an error is already a string, so [Display] errors are always formatted
+/// into an owned message, by [SoftResult::soft_err_to_message]. This trait is the borrowing fast path on top of it,
+/// used by [SoftResult::soft_err_display].
+///
+/// Implemented for string types, which are borrowed as is, for [Escalated], with the message of the escalated soft error,
+/// and for [SoftErrors], which is formatted.
+/// Errors with computed messages can return an owned message, for example with `Cow::Owned(self.to_string())`.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use try_hard::{SoftMessage, SoftResult};
+///
+/// struct TooLong(usize);
+///
+/// impl SoftMessage for TooLong {
+///     fn message(&self) -> Cow<'_, str> {
+///         Cow::Owned(format!("too long by {} characters", self.0))
+///     }
+/// }
+///
+/// let soft_result: SoftResult<(), TooLong> = SoftResult::SoftErr(TooLong(3));
+/// assert_eq!(soft_result.soft_err_display().as_deref(), Some("too long by 3 characters"));
+/// ```
+pub trait SoftMessage {
+    /// The message of this soft error.
+    fn message(&self) -> Cow<'_, str>;
+}
+
+impl SoftMessage for str {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl SoftMessage for String {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl SoftMessage for Cow<'_, str> {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<M: SoftMessage + ?Sized> SoftMessage for &M {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        (**self).message()
+    }
+}
+
+impl<E: SoftMessage> SoftMessage for Escalated<E> {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        self.0.message()
+    }
+}
+
+impl<E: Display> SoftMessage for SoftErrors<E> {
+    #[inline]
+    fn message(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl<T, E: SoftMessage> SoftResult<T, E> {
+    /// Returns the [SoftMessage] of the soft error, or [None] if the result is [SoftResult::Ok].
+    /// The message is borrowed when the soft error is a string, so no [String] is allocated in the common case.
+    /// Requires the `alloc` feature.
+    #[inline]
+    pub fn soft_err_display(&self) -> Option<Cow<'_, str>> {
+        match self {
+            SoftResult::Ok(_) => None,
+            SoftResult::SoftErr(e) => Some(e.message()),
+        }
+    }
+}
+
+impl<T, E: Display> SoftResult<T, E> {
+    /// Returns the [Display] of the soft error as an owned message, or [None] if the result is [SoftResult::Ok].
+    /// Works for any [Display] error, use [SoftResult::soft_err_display] to borrow the message of a [SoftMessage].
+    /// Requires the `alloc` feature.
+    ///
+    /// ```rust
+    /// use try_hard::SoftResult;
+    ///
+    /// let soft_result: SoftResult<(), std::fmt::Error> = SoftResult::SoftErr(std::fmt::Error);
+    /// assert_eq!(
+    ///     soft_result.soft_err_to_message().as_deref(),
+    ///     Some("an error occurred when formatting an argument")
+    /// );
+    /// ```
+    #[inline]
+    pub fn soft_err_to_message(&self) -> Option<Cow<'_, str>> {
+        match self {
+            SoftResult::Ok(_) => None,
+            SoftResult::SoftErr(e) => Some(Cow::Owned(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TooLong(usize);
+
+    impl Display for TooLong {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "too long by {}", self.0)
+        }
+    }
+
+    #[test]
+    fn check_soft_err_display_borrows_strings() {
+        let soft_result: SoftResult<(), &'static str> = SoftResult::SoftErr("empty name");
+        assert!(matches!(
+            soft_result.soft_err_display(),
+            Some(Cow::Borrowed("empty name"))
+        ));
+
+        let soft_result: SoftResult<(), String> = SoftResult::SoftErr("empty name".to_string());
+        assert!(matches!(
+            soft_result.soft_err_display(),
+            Some(Cow::Borrowed("empty name"))
+        ));
+    }
+
+    #[test]
+    fn check_soft_err_to_message_owns_computed_messages() {
+        let soft_result: SoftResult<(), TooLong> = SoftResult::SoftErr(TooLong(3));
+        assert!(matches!(
+            soft_result.soft_err_to_message(),
+            Some(Cow::Owned(message)) if message == "too long by 3"
+        ));
+    }
+
+    #[test]
+    fn check_soft_err_display_of_wrappers() {
+        let soft_result: SoftResult<(), Escalated<&str>> =
+            SoftResult::SoftErr(Escalated("empty name"));
+        assert!(matches!(
+            soft_result.soft_err_display(),
+            Some(Cow::Borrowed("empty name"))
+        ));
+
+        let soft_result: SoftResult<(), SoftErrors<TooLong>> =
+            SoftResult::SoftErr(SoftErrors(vec![TooLong(1), TooLong(2)]));
+        assert!(matches!(
+            soft_result.soft_err_display(),
+            Some(Cow::Owned(message)) if message == "too long by 1; too long by 2"
+        ));
+    }
+
+    #[test]
+    fn check_soft_err_display_on_ok() {
+        let soft_result: SoftResult<i32, &str> = SoftResult::Ok(1);
+        assert_eq!(soft_result.soft_err_display(), None);
+        assert_eq!(soft_result.soft_err_to_message(), None);
+    }
+}
@@ -0,0 +1,104 @@
+//! Implements the unstable [Try] trait for [SoftResult], so the `?` operator works on it.
+//! Requires a nightly compiler and the `nightly` feature.
+//!
+//! The residual of a [SoftResult] is its [SoftResult::SoftErr] case.
+//! Using `?` on a [SoftResult] inside a function returning a [MalleableResult](crate::MalleableResult)
+//! short-circuits with `Ok(SoftResult::SoftErr(_))`, just like [try_soft](crate::try_soft).
+
+use core::convert::Infallible;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+use crate::SoftResult;
+
+impl<T, E> Try for SoftResult<T, E> {
+    type Output = T;
+    type Residual = SoftResult<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        SoftResult::Ok(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            SoftResult::Ok(t) => ControlFlow::Continue(t),
+            SoftResult::SoftErr(e) => ControlFlow::Break(SoftResult::SoftErr(e)),
+        }
+    }
+}
+
+impl<T, E> Residual<T> for SoftResult<Infallible, E> {
+    type TryType = SoftResult<T, E>;
+}
+
+impl<T, E, F: From<E>> FromResidual<SoftResult<Infallible, E>> for SoftResult<T, F> {
+    #[inline]
+    fn from_residual(residual: SoftResult<Infallible, E>) -> Self {
+        match residual {
+            SoftResult::Ok(never) => match never {},
+            SoftResult::SoftErr(e) => SoftResult::SoftErr(From::from(e)),
+        }
+    }
+}
+
+impl<T, E, F: From<E>, H> FromResidual<SoftResult<Infallible, E>> for Result<SoftResult<T, F>, H> {
+    #[inline]
+    fn from_residual(residual: SoftResult<Infallible, E>) -> Self {
+        Ok(SoftResult::from_residual(residual))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{MalleableResult, SoftResult};
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct HardError;
+
+    fn question_soft(soft_result: SoftResult<i32, SoftError>) -> SoftResult<i32, SoftError> {
+        let x = soft_result?;
+        SoftResult::Ok(x + 1)
+    }
+
+    fn question_malleable(
+        soft_result: SoftResult<i32, SoftError>,
+        hard_result: Result<(), HardError>,
+    ) -> MalleableResult<i32, SoftError, HardError> {
+        let x = soft_result?;
+        hard_result?;
+        Ok(SoftResult::Ok(x + 1))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), SoftResult::Ok(2))]
+    #[case(SoftResult::SoftErr(SoftError), SoftResult::SoftErr(SoftError))]
+    fn check_question_mark_soft(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] expected: SoftResult<i32, SoftError>,
+    ) {
+        assert_eq!(question_soft(soft_result), expected);
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(1), Ok(()), Ok(SoftResult::Ok(2)))]
+    #[case(SoftResult::SoftErr(SoftError), Ok(()), Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(
+        SoftResult::SoftErr(SoftError),
+        Err(HardError),
+        Ok(SoftResult::SoftErr(SoftError))
+    )]
+    #[case(SoftResult::Ok(1), Err(HardError), Err(HardError))]
+    fn check_question_mark_malleable(
+        #[case] soft_result: SoftResult<i32, SoftError>,
+        #[case] hard_result: Result<(), HardError>,
+        #[case] expected: MalleableResult<i32, SoftError, HardError>,
+    ) {
+        assert_eq!(question_malleable(soft_result, hard_result), expected);
+    }
+}
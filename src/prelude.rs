@@ -0,0 +1,52 @@
+//! Re-exports the types, extension traits and macros of the crate, for a single glob import.
+//!
+//! ```rust
+//! use try_hard::prelude::*;
+//!
+//! fn parse(input: &str) -> MalleableResult<i32, std::num::ParseIntError, String> {
+//!     let parsed = try_soft!(input.parse::<i32>().soften());
+//!     Ok(SoftResult::Ok(parsed))
+//! }
+//!
+//! assert!(parse("forty two").is_soft_err());
+//! ```
+
+#[cfg(feature = "anyhow")]
+#[doc(inline)]
+pub use crate::try_hard_anyhow;
+#[cfg(feature = "eyre")]
+#[doc(inline)]
+pub use crate::try_hard_eyre;
+#[cfg(feature = "log_json")]
+#[doc(inline)]
+pub use crate::try_hard_json;
+#[cfg(feature = "futures")]
+#[doc(inline)]
+pub use crate::try_join_hard;
+#[doc(inline)]
+pub use crate::{
+    assert_hard_err, assert_soft_err, assert_soft_ok, ensure_hard, ensure_soft, hard_bail,
+    soft_bail, soft_let, try_escalate, try_hard, try_hard_into, try_soft, try_soft_else,
+    try_soft_or,
+};
+#[cfg(feature = "tracing")]
+#[doc(inline)]
+pub use crate::{
+    record_soft, try_hard_at, try_hard_audit, try_hard_log, try_hard_record, try_hard_traced,
+    try_soft_traced, try_soft_with_span,
+};
+
+#[cfg(feature = "anyhow")]
+pub use crate::AnyhowExt;
+#[cfg(feature = "eyre")]
+pub use crate::EyreExt;
+#[cfg(feature = "alloc")]
+pub use crate::SoftMessage;
+#[cfg(feature = "macros")]
+pub use crate::malleable;
+pub use crate::{
+    FlattenMalleable, MalleableResult, MalleableResultExt, OptionExt, ResultExt, SoftResult,
+    SoftStatus,
+};
+#[cfg(feature = "futures")]
+pub use crate::{MalleableFutureExt, MalleableStreamExt};
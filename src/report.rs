@@ -0,0 +1,180 @@
+//! Integration with error report crates for the hard error channel, while keeping soft errors typed.
+//! Requires the `anyhow` or the `eyre` feature.
+
+use crate::{MalleableResult, SoftResult};
+
+/// Converts the hard error of a [MalleableResult] into an [anyhow::Error].
+#[cfg(feature = "anyhow")]
+pub trait AnyhowExt<T, S> {
+    /// Converts the hard error into an [anyhow::Error], leaving ok values and soft errors untouched.
+    fn harden_anyhow(self) -> MalleableResult<T, S, anyhow::Error>;
+}
+
+#[cfg(feature = "anyhow")]
+impl<T, S, E: Into<anyhow::Error>> AnyhowExt<T, S> for Result<SoftResult<T, S>, E> {
+    #[inline]
+    fn harden_anyhow(self) -> MalleableResult<T, S, anyhow::Error> {
+        self.map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but converts the hard error into an `anyhow::Error` before short-circuiting.
+macro_rules! try_hard_anyhow {
+    ($e:expr) => {
+        match $e {
+            ::core::result::Result::Ok(t) => $crate::try_soft!(t),
+            ::core::result::Result::Err(e) => {
+                return ::core::result::Result::Err($crate::__private::anyhow::Error::from(e));
+            }
+        }
+    };
+}
+
+/// Converts the hard error of a [MalleableResult] into an [eyre::Report].
+#[cfg(feature = "eyre")]
+pub trait EyreExt<T, S> {
+    /// Converts the hard error into an [eyre::Report], leaving ok values and soft errors untouched.
+    fn into_eyre_hard(self) -> MalleableResult<T, S, eyre::Report>;
+}
+
+#[cfg(feature = "eyre")]
+impl<T, S, E: Into<eyre::Report>> EyreExt<T, S> for Result<SoftResult<T, S>, E> {
+    #[inline]
+    fn into_eyre_hard(self) -> MalleableResult<T, S, eyre::Report> {
+        self.map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "eyre")]
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but converts the hard error into an `eyre::Report` before short-circuiting.
+macro_rules! try_hard_eyre {
+    ($e:expr) => {
+        match $e {
+            ::core::result::Result::Ok(t) => $crate::try_soft!(t),
+            ::core::result::Result::Err(e) => {
+                return ::core::result::Result::Err($crate::__private::eyre::Report::from(e));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+    #[error("a real dangerous error")]
+    struct HardError;
+
+    #[cfg(feature = "anyhow")]
+    fn tries_hard_anyhow(
+        hard_result: MalleableResult<i32, SoftError, HardError>,
+    ) -> MalleableResult<i32, SoftError, anyhow::Error> {
+        let x = try_hard_anyhow!(hard_result);
+        Ok(SoftResult::Ok(x + 1))
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Some(SoftResult::Ok(2)))]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Some(SoftResult::SoftErr(SoftError))
+    )]
+    #[case(Err(HardError), None)]
+    fn check_try_hard_anyhow(
+        #[case] hard_result: MalleableResult<i32, SoftError, HardError>,
+        #[case] expected: Option<SoftResult<i32, SoftError>>,
+    ) {
+        match tries_hard_anyhow(hard_result) {
+            Ok(soft_result) => assert_eq!(Some(soft_result), expected),
+            Err(e) => {
+                assert_eq!(expected, None);
+                assert_eq!(e.downcast_ref::<HardError>(), Some(&HardError));
+            }
+        }
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)))]
+    #[case(Ok(SoftResult::SoftErr(SoftError)))]
+    #[case(Err(HardError))]
+    fn check_harden_anyhow(#[case] hard_result: MalleableResult<i32, SoftError, HardError>) {
+        let result = hard_result.clone().harden_anyhow();
+        match (result, hard_result) {
+            (Ok(soft_result), Ok(expected)) => assert_eq!(soft_result, expected),
+            (Err(e), Err(expected)) => {
+                assert_eq!(e.to_string(), "a real dangerous error");
+                assert_eq!(e.downcast::<HardError>().unwrap(), expected);
+            }
+            (result, expected) => panic!("{result:?} is not {expected:?}"),
+        }
+    }
+
+    #[cfg(feature = "eyre")]
+    #[derive(Debug, thiserror::Error)]
+    #[error("query failed")]
+    struct QueryError(#[source] HardError);
+
+    #[cfg(feature = "eyre")]
+    fn tries_hard_eyre(
+        hard_result: MalleableResult<i32, SoftError, QueryError>,
+    ) -> MalleableResult<i32, SoftError, eyre::Report> {
+        let x = try_hard_eyre!(hard_result);
+        Ok(SoftResult::Ok(x + 1))
+    }
+
+    #[cfg(feature = "eyre")]
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Some(SoftResult::Ok(2)))]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Some(SoftResult::SoftErr(SoftError))
+    )]
+    #[case(Err(QueryError(HardError)), None)]
+    fn check_try_hard_eyre(
+        #[case] hard_result: MalleableResult<i32, SoftError, QueryError>,
+        #[case] expected: Option<SoftResult<i32, SoftError>>,
+    ) {
+        match tries_hard_eyre(hard_result) {
+            Ok(soft_result) => assert_eq!(Some(soft_result), expected),
+            Err(report) => {
+                assert_eq!(expected, None);
+                let chain: Vec<String> = report.chain().map(ToString::to_string).collect();
+                assert_eq!(chain, ["query failed", "a real dangerous error"]);
+            }
+        }
+    }
+
+    #[cfg(feature = "eyre")]
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(1)), Some(SoftResult::Ok(1)))]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Some(SoftResult::SoftErr(SoftError))
+    )]
+    #[case(Err(QueryError(HardError)), None)]
+    fn check_into_eyre_hard(
+        #[case] hard_result: MalleableResult<i32, SoftError, QueryError>,
+        #[case] expected: Option<SoftResult<i32, SoftError>>,
+    ) {
+        match hard_result.into_eyre_hard() {
+            Ok(soft_result) => assert_eq!(Some(soft_result), expected),
+            Err(report) => {
+                assert_eq!(expected, None);
+                assert!(report.downcast_ref::<QueryError>().is_some());
+                let chain: Vec<String> = report.chain().map(ToString::to_string).collect();
+                assert_eq!(chain, ["query failed", "a real dangerous error"]);
+            }
+        }
+    }
+}
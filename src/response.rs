@@ -0,0 +1,118 @@
+//! Integration with `axum`, so handlers can return a [MalleableResult](crate::MalleableResult).
+//! Requires the `axum` feature.
+//!
+//! [SoftResult] implements [IntoResponse], using the response of either the ok value or the soft error.
+//! Since `axum` implements [IntoResponse] for [Result], this makes any [MalleableResult](crate::MalleableResult)
+//! a valid handler return type, as long as its ok value, soft error and hard error implement [IntoResponse].
+//! The hard error response should use a 500-class status, as hard errors are not the user's fault.
+//!
+//! With the `serde` feature, [SoftResult::into_response_parts] builds a JSON response, using the [SoftStatus](crate::SoftStatus) of the soft error.
+
+use axum::response::{IntoResponse, Response};
+#[cfg(feature = "serde")]
+use axum::{Json, http::StatusCode};
+
+use crate::SoftResult;
+#[cfg(feature = "serde")]
+use crate::SoftStatus;
+
+impl<T: IntoResponse, S: IntoResponse> IntoResponse for SoftResult<T, S> {
+    fn into_response(self) -> Response {
+        match self {
+            SoftResult::Ok(t) => t.into_response(),
+            SoftResult::SoftErr(s) => s.into_response(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, E: serde::Serialize + SoftStatus> SoftResult<T, E> {
+    /// Builds the status code and JSON body of a response: `200 OK` with the ok value, or the [SoftStatus] of the soft error with the soft error.
+    /// A status code outside of the valid range is replaced with `500 Internal Server Error`.
+    /// The returned tuple implements [IntoResponse], so it can be returned from a handler directly.
+    /// Requires the `axum` and `serde` features.
+    pub fn into_response_parts(self) -> (StatusCode, SoftResult<Json<T>, Json<E>>) {
+        match self {
+            SoftResult::Ok(t) => (StatusCode::OK, SoftResult::Ok(Json(t))),
+            SoftResult::SoftErr(e) => {
+                let status = StatusCode::from_u16(e.status_code())
+                    .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                (status, SoftResult::SoftErr(Json(e)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::MalleableResult;
+
+    struct NotFound;
+
+    impl IntoResponse for NotFound {
+        fn into_response(self) -> Response {
+            (StatusCode::NOT_FOUND, "not found").into_response()
+        }
+    }
+
+    struct DatabaseFailure;
+
+    impl IntoResponse for DatabaseFailure {
+        fn into_response(self) -> Response {
+            (StatusCode::INTERNAL_SERVER_ERROR, "database failure").into_response()
+        }
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok("hello")), StatusCode::OK)]
+    #[case(Ok(SoftResult::SoftErr(NotFound)), StatusCode::NOT_FOUND)]
+    #[case(Err(DatabaseFailure), StatusCode::INTERNAL_SERVER_ERROR)]
+    fn check_into_response(
+        #[case] result: MalleableResult<&'static str, NotFound, DatabaseFailure>,
+        #[case] expected: StatusCode,
+    ) {
+        assert_eq!(result.into_response().status(), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(serde::Serialize)]
+    struct Invalid {
+        field: &'static str,
+    }
+
+    #[cfg(feature = "serde")]
+    impl SoftStatus for Invalid {
+        fn status_code(&self) -> u16 {
+            422
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case(SoftResult::Ok(vec![1, 2]), StatusCode::OK, "[1,2]")]
+    #[case(
+        SoftResult::SoftErr(Invalid { field: "name" }),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        r#"{"field":"name"}"#
+    )]
+    #[tokio::test]
+    async fn check_into_response_parts(
+        #[case] soft_result: SoftResult<Vec<i32>, Invalid>,
+        #[case] expected_status: StatusCode,
+        #[case] expected_body: &str,
+    ) {
+        let (status, body) = soft_result.into_response_parts();
+        assert_eq!(status, expected_status);
+        let response = (status, body).into_response();
+        assert_eq!(response.status(), expected_status);
+        assert_eq!(response.headers()["content-type"], "application/json");
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(bytes, expected_body.as_bytes());
+    }
+}
@@ -0,0 +1,10 @@
+//! HTTP status codes for soft errors.
+
+/// Maps a soft error to an HTTP status code.
+///
+/// Soft errors are the user's fault, so they should map to 4xx status codes,
+/// while hard errors are reserved to 5xx status codes.
+pub trait SoftStatus {
+    /// The HTTP status code of this soft error, such as `404`.
+    fn status_code(&self) -> u16;
+}
@@ -0,0 +1,106 @@
+//! Stream adapters for streams of [MalleableResult]s.
+//! Requires the `futures` feature.
+
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::MalleableResult;
+
+/// Extends streams of [MalleableResult]s with adapters that handle soft and hard errors differently.
+pub trait MalleableStreamExt<T, S, H>: Stream<Item = MalleableResult<T, S, H>> {
+    /// Passes ok values and soft errors through, but terminates the stream after the first hard error.
+    /// The hard error itself is yielded, and the underlying stream is not polled again.
+    fn split_hard(self) -> SplitHard<Self>
+    where
+        Self: Sized,
+    {
+        SplitHard {
+            stream: self,
+            done: false,
+        }
+    }
+}
+
+impl<T, S, H, St> MalleableStreamExt<T, S, H> for St where
+    St: Stream<Item = MalleableResult<T, S, H>>
+{
+}
+
+pin_project! {
+    /// Stream for the [MalleableStreamExt::split_hard] method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SplitHard<St> {
+        #[pin]
+        stream: St,
+        done: bool,
+    }
+}
+
+impl<T, S, H, St> Stream for SplitHard<St>
+where
+    St: Stream<Item = MalleableResult<T, S, H>>,
+{
+    type Item = MalleableResult<T, S, H>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        let item = ready!(this.stream.poll_next(cx));
+        *this.done = !matches!(item, Some(Ok(_)));
+        Poll::Ready(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::SoftResult;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct SoftError;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct HardError;
+
+    #[tokio::test]
+    async fn check_split_hard() {
+        let items: Vec<MalleableResult<i32, SoftError, HardError>> = vec![
+            Ok(SoftResult::Ok(1)),
+            Ok(SoftResult::SoftErr(SoftError)),
+            Ok(SoftResult::Ok(2)),
+            Err(HardError),
+            Ok(SoftResult::Ok(3)),
+        ];
+        let polled = std::cell::Cell::new(0);
+        let stream = futures::stream::iter(items).inspect(|_| polled.set(polled.get() + 1));
+        let collected: Vec<_> = stream.split_hard().collect().await;
+        assert_eq!(
+            collected,
+            vec![
+                Ok(SoftResult::Ok(1)),
+                Ok(SoftResult::SoftErr(SoftError)),
+                Ok(SoftResult::Ok(2)),
+                Err(HardError),
+            ]
+        );
+        assert_eq!(polled.get(), 4);
+    }
+
+    #[tokio::test]
+    async fn check_split_hard_without_hard_errors() {
+        let items: Vec<MalleableResult<i32, SoftError, HardError>> =
+            vec![Ok(SoftResult::Ok(1)), Ok(SoftResult::SoftErr(SoftError))];
+        let collected: Vec<_> = futures::stream::iter(items.clone())
+            .split_hard()
+            .collect()
+            .await;
+        assert_eq!(collected, items);
+    }
+}
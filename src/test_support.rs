@@ -0,0 +1,134 @@
+//! Helpers to capture the `tracing` events emitted by the macros under test.
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+/// An event recorded by [capture].
+#[derive(Debug, Clone)]
+pub(crate) struct CapturedEvent {
+    pub(crate) target: &'static str,
+    pub(crate) level: Level,
+    pub(crate) fields: Vec<(String, String)>,
+}
+
+impl CapturedEvent {
+    /// Returns the recorded value of the field `name`, if any.
+    pub(crate) fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A span recorded by [capture_spans], with the fields recorded at its creation and afterwards,
+/// and how many times it was entered and exited.
+#[derive(Debug, Clone)]
+pub(crate) struct CapturedSpan {
+    pub(crate) id: Id,
+    pub(crate) name: &'static str,
+    pub(crate) fields: Vec<(String, String)>,
+    pub(crate) entered: usize,
+    pub(crate) exited: usize,
+}
+
+impl CapturedSpan {
+    /// Returns the last recorded value of the field `name`, if any.
+    pub(crate) fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .rev()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+#[derive(Default, Clone)]
+struct CaptureLayer {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = Vec::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().push(CapturedSpan {
+            id: id.clone(),
+            name: attrs.metadata().name(),
+            fields,
+            entered: 0,
+            exited: 0,
+        });
+    }
+
+    fn on_enter(&self, id: &Id, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(span) = spans.iter_mut().find(|span| span.id == *id) {
+            span.entered += 1;
+        }
+    }
+
+    fn on_exit(&self, id: &Id, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(span) = spans.iter_mut().find(|span| span.id == *id) {
+            span.exited += 1;
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some(span) = spans.iter_mut().find(|span| span.id == *id) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = Vec::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events.lock().unwrap().push(CapturedEvent {
+            target: event.metadata().target(),
+            level: *event.metadata().level(),
+            fields,
+        });
+    }
+}
+
+fn run<R>(f: impl FnOnce() -> R) -> (R, CaptureLayer) {
+    let layer = CaptureLayer::default();
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    let result = tracing::subscriber::with_default(subscriber, f);
+    (result, layer)
+}
+
+/// Runs `f` with a subscriber that records every event, returning its result and the recorded events.
+pub(crate) fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<CapturedEvent>) {
+    let (result, layer) = run(f);
+    let events = layer.events.lock().unwrap().clone();
+    (result, events)
+}
+
+/// Runs `f` with a subscriber that records every span, returning its result and the recorded spans.
+pub(crate) fn capture_spans<R>(f: impl FnOnce() -> R) -> (R, Vec<CapturedSpan>) {
+    let (result, layer) = run(f);
+    let spans = layer.spans.lock().unwrap().clone();
+    (result, spans)
+}
@@ -0,0 +1,84 @@
+//! Timeouts that fail hard, for futures resolving to a [MalleableResult].
+//! Requires the `tokio` feature.
+
+use core::future::Future;
+use core::time::Duration;
+
+use crate::MalleableResult;
+
+/// Awaits `fut` for at most `dur`, converting an elapsed timeout into the hard error `Err(on_timeout())`.
+/// On timeout, `fut` is dropped, cancelling it.
+///
+/// Must be called from within a `tokio` runtime with the time driver enabled.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use try_hard::{with_hard_timeout, MalleableResult, SoftResult};
+///
+/// async fn fetch() -> MalleableResult<i32, String, String> {
+///     Ok(SoftResult::Ok(1))
+/// }
+///
+/// async fn handler() -> MalleableResult<i32, String, String> {
+///     with_hard_timeout(Duration::from_secs(5), fetch(), || "timed out".to_string()).await
+/// }
+/// ```
+pub async fn with_hard_timeout<T, S, H, Fut, F>(
+    dur: Duration,
+    fut: Fut,
+    on_timeout: F,
+) -> MalleableResult<T, S, H>
+where
+    Fut: Future<Output = MalleableResult<T, S, H>>,
+    F: FnOnce() -> H,
+{
+    match tokio::time::timeout(dur, fut).await {
+        Ok(result) => result,
+        Err(_) => Err(on_timeout()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::SoftResult;
+
+    async fn sleepy(
+        delay: Duration,
+        result: MalleableResult<i32, &'static str, &'static str>,
+        completed: &AtomicBool,
+    ) -> MalleableResult<i32, &'static str, &'static str> {
+        tokio::time::sleep(delay).await;
+        completed.store(true, Ordering::SeqCst);
+        result
+    }
+
+    #[rstest]
+    #[case(Duration::from_secs(1), Ok(SoftResult::Ok(1)), Ok(SoftResult::Ok(1)))]
+    #[case(
+        Duration::from_secs(1),
+        Ok(SoftResult::SoftErr("soft")),
+        Ok(SoftResult::SoftErr("soft"))
+    )]
+    #[case(Duration::from_secs(1), Err("hard"), Err("hard"))]
+    #[case(Duration::from_secs(60), Ok(SoftResult::Ok(1)), Err("timed out"))]
+    #[tokio::test(start_paused = true)]
+    async fn check_with_hard_timeout(
+        #[case] delay: Duration,
+        #[case] result: MalleableResult<i32, &'static str, &'static str>,
+        #[case] expected: MalleableResult<i32, &'static str, &'static str>,
+    ) {
+        let timeout = Duration::from_secs(10);
+        let completed = AtomicBool::new(false);
+        let fut = sleepy(delay, result, &completed);
+        let timed = with_hard_timeout(timeout, fut, || "timed out").await;
+        assert_eq!(timed, expected);
+        // A cancelled future never completes, even once its delay has elapsed.
+        tokio::time::sleep(delay).await;
+        assert_eq!(completed.load(Ordering::SeqCst), delay < timeout);
+    }
+}
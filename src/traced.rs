@@ -0,0 +1,698 @@
+//! Variants of the [try_soft](crate::try_soft) and [try_hard](crate::try_hard) macros, and [SoftResult] methods, that emit `tracing` events.
+//! Like [try_soft](crate::try_soft) and [try_hard](crate::try_hard), the macros convert the errors with [Into] before returning them.
+//! Requires the `tracing` feature.
+
+use core::fmt::Display;
+
+use tracing::Level;
+
+use crate::{MalleableResult, SoftResult};
+
+impl<T, E: Display> SoftResult<T, E> {
+    /// Emits an event at `level` with the [Display] of the soft error in its `soft_error` field, then returns the result unchanged.
+    /// No event is emitted for a [SoftResult::Ok]. Unlike the macros, the level can be chosen at runtime.
+    /// Requires the `tracing` feature.
+    pub fn inspect_soft_err_traced(self, level: Level) -> Self {
+        if let SoftResult::SoftErr(e) = &self {
+            if level == Level::ERROR {
+                tracing::error!(soft_error = %e, "soft error");
+            } else if level == Level::WARN {
+                tracing::warn!(soft_error = %e, "soft error");
+            } else if level == Level::INFO {
+                tracing::info!(soft_error = %e, "soft error");
+            } else if level == Level::DEBUG {
+                tracing::debug!(soft_error = %e, "soft error");
+            } else {
+                tracing::trace!(soft_error = %e, "soft error");
+            }
+        }
+        self
+    }
+}
+
+/// Emits an `error` event for a hard error or a `warn` event for a soft error, without consuming the result.
+/// The event carries the [Display] of the error in its `hard_error` or `soft_error` field. No event is emitted for an ok result.
+/// This lets middleware log every [MalleableResult] uniformly. Requires the `tracing` feature.
+///
+/// ```rust
+/// use try_hard::{observe_malleable, MalleableResult, SoftResult};
+///
+/// let result: MalleableResult<i32, &str, &str> = Ok(SoftResult::SoftErr("not found"));
+/// observe_malleable(&result);
+/// assert!(result.is_ok());
+/// ```
+pub fn observe_malleable<T, S: Display, H: Display>(result: &MalleableResult<T, S, H>) {
+    match result {
+        Ok(SoftResult::Ok(_)) => {}
+        Ok(SoftResult::SoftErr(s)) => tracing::warn!(soft_error = %s, "soft error"),
+        Err(h) => tracing::error!(hard_error = %h, "hard error"),
+    }
+}
+
+#[macro_export]
+/// Works like [try_soft](crate::try_soft), but emits a `warn` event before short-circuiting on a [SoftResult::SoftErr](crate::SoftResult::SoftErr).
+/// The event carries the [Display](core::fmt::Display) of the soft error in its `soft_error` field.
+macro_rules! try_soft_traced {
+    ($e:expr) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(e) => {
+                $crate::__private::tracing::warn!(soft_error = %e, "soft error");
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but emits a `warn` event before short-circuiting on a [SoftResult::SoftErr](crate::SoftResult::SoftErr),
+/// like [try_soft_traced](crate::try_soft_traced).
+/// Hard errors are returned without an event, since `#[instrument(err)]` already reports them.
+macro_rules! try_hard_traced {
+    ($e:expr) => {
+        match $e {
+            ::core::result::Result::Ok(t) => $crate::try_soft_traced!(t),
+            ::core::result::Result::Err(e) => {
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but emits an event at the configured level before short-circuiting.
+/// Soft errors are reported in the `soft_error` field, and hard errors in the `hard_error` field, with their [Display](core::fmt::Display).
+///
+/// The levels must be constants, such as `tracing::Level::WARN`:
+///
+/// ```rust
+/// use tracing::Level;
+/// use try_hard::{try_hard_at, MalleableResult, SoftResult};
+///
+/// fn fetch() -> MalleableResult<i32, String, String> {
+///     Ok(SoftResult::SoftErr("not found".to_string()))
+/// }
+///
+/// fn handler() -> MalleableResult<i32, String, String> {
+///     let x = try_hard_at!(fetch(), hard = Level::ERROR, soft = Level::DEBUG);
+///     Ok(SoftResult::Ok(x))
+/// }
+/// ```
+macro_rules! try_hard_at {
+    ($e:expr, hard = $hard:expr, soft = $soft:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => t,
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                $crate::__private::tracing::event!($soft, soft_error = %e, "soft error");
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                $crate::__private::tracing::event!($hard, hard_error = %e, "hard error");
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but emits its events with an explicit `tracing` target,
+/// so they can be filtered per logging namespace.
+/// Soft errors emit a `warn` event with the `soft_error` field, and hard errors an `error` event with the `hard_error` field.
+///
+/// The target must be a constant, like in the `tracing` macros:
+///
+/// ```rust
+/// use try_hard::{try_hard_log, MalleableResult, SoftResult};
+///
+/// fn query() -> MalleableResult<i32, String, String> {
+///     Err("connection lost".to_string())
+/// }
+///
+/// fn handler() -> MalleableResult<i32, String, String> {
+///     let x = try_hard_log!(target: "db", query());
+///     Ok(SoftResult::Ok(x))
+/// }
+/// ```
+macro_rules! try_hard_log {
+    (target: $target:expr, $e:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => t,
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                $crate::__private::tracing::warn!(target: $target, soft_error = %e, "soft error");
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                $crate::__private::tracing::error!(target: $target, hard_error = %e, "hard error");
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard_log](crate::try_hard_log), but also emits an `info` event on success, for audit trails.
+/// Every event carries `event_name` in its `audit_event` field, so successful and failed operations can be correlated.
+/// As usual, soft errors emit a `warn` event and hard errors an `error` event.
+///
+/// ```rust
+/// use try_hard::{try_hard_audit, MalleableResult, SoftResult};
+///
+/// fn transfer(result: MalleableResult<u32, String, String>) -> MalleableResult<u32, String, String> {
+///     let amount = try_hard_audit!(result, "transfer");
+///     Ok(SoftResult::Ok(amount))
+/// }
+/// # assert_eq!(transfer(Ok(SoftResult::Ok(10))), Ok(SoftResult::Ok(10)));
+/// ```
+macro_rules! try_hard_audit {
+    ($e:expr, $event_name:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => {
+                $crate::__private::tracing::info!(audit_event = $event_name, "success");
+                t
+            }
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                $crate::__private::tracing::warn!(audit_event = $event_name, soft_error = %e, "soft error");
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                $crate::__private::tracing::error!(audit_event = $event_name, hard_error = %e, "hard error");
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_soft](crate::try_soft), but evaluates the expression inside an `info` span named `$name`, with [Span::in_scope](tracing::Span::in_scope).
+/// This attributes the evaluation to a named span, even outside of `#[instrument]`.
+/// The span is exited once the expression is evaluated, before the short-circuit or continue paths.
+///
+/// The expression is evaluated in a closure, so it cannot `.await`, `return` or use `?`.
+/// In async code, attach the span to the future with [Instrument](tracing::Instrument) instead,
+/// since holding an entered span across an `.await` attributes it to the wrong task.
+///
+/// The name must be a constant, like in the `tracing` macros:
+///
+/// ```rust
+/// use try_hard::{try_soft_with_span, MalleableResult, SoftResult};
+///
+/// fn lookup() -> SoftResult<i32, String> {
+///     SoftResult::Ok(1)
+/// }
+///
+/// fn handler() -> MalleableResult<i32, String, String> {
+///     let x = try_soft_with_span!("lookup", lookup());
+///     Ok(SoftResult::Ok(x))
+/// }
+/// ```
+macro_rules! try_soft_with_span {
+    ($name:expr, $e:expr $(,)?) => {{
+        let span = $crate::__private::tracing::info_span!($name);
+        match span.in_scope(|| $e) {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(e) => {
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+        }
+    }};
+}
+
+#[macro_export]
+/// Works like [try_soft](crate::try_soft), but records the soft error onto the current span before short-circuiting.
+/// The soft error is recorded with its [Display](core::fmt::Display) in the field `$field`,
+/// which must be declared when the span is created, for example with `#[instrument(fields(soft_error))]`.
+macro_rules! record_soft {
+    ($e:expr, $field:expr $(,)?) => {
+        match $e {
+            $crate::SoftResult::Ok(t) => t,
+            $crate::SoftResult::SoftErr(e) => {
+                $crate::__private::tracing::Span::current()
+                    .record($field, $crate::__private::tracing::field::display(&e));
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but records the error onto the current span before short-circuiting.
+/// Soft errors are recorded in the `soft_error` field, and hard errors in the `hard_error` field, with their [Display](core::fmt::Display).
+/// Both fields must be declared when the span is created, for example with `#[instrument(fields(soft_error, hard_error))]`.
+macro_rules! try_hard_record {
+    ($e:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => t,
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                $crate::__private::tracing::Span::current()
+                    .record("soft_error", $crate::__private::tracing::field::display(&e));
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                $crate::__private::tracing::Span::current()
+                    .record("hard_error", $crate::__private::tracing::field::display(&e));
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "log_json")]
+#[macro_export]
+/// Works like [try_hard](crate::try_hard), but emits an event with the error serialized as JSON before short-circuiting.
+/// Soft errors emit a `warn` event, and hard errors an `error` event, both with the JSON in the `error_json` field.
+/// If serialization fails, the field holds the serialization error message instead.
+/// Requires the `log_json` feature, and both error types to implement `Serialize`.
+macro_rules! try_hard_json {
+    ($e:expr $(,)?) => {
+        match $e {
+            ::core::result::Result::Ok($crate::SoftResult::Ok(t)) => t,
+            ::core::result::Result::Ok($crate::SoftResult::SoftErr(e)) => {
+                let error_json = $crate::__private::serde_json::to_string(&e).unwrap_or_else(|err| err.to_string());
+                $crate::__private::tracing::warn!(error_json = %error_json, "soft error");
+                return ::core::result::Result::Ok($crate::SoftResult::SoftErr(
+                    ::core::convert::Into::into(e),
+                ));
+            }
+            ::core::result::Result::Err(e) => {
+                let error_json = $crate::__private::serde_json::to_string(&e).unwrap_or_else(|err| err.to_string());
+                $crate::__private::tracing::error!(error_json = %error_json, "hard error");
+                return ::core::result::Result::Err(::core::convert::Into::into(e));
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tracing::{Level, instrument};
+
+    use super::observe_malleable;
+    use crate::test_support::{capture, capture_spans};
+    use crate::{MalleableResult, SoftResult};
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+    #[cfg_attr(feature = "log_json", derive(serde::Serialize))]
+    #[error("a soft error")]
+    struct SoftError;
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+    #[cfg_attr(feature = "log_json", derive(serde::Serialize))]
+    #[error("a real dangerous error")]
+    struct HardError;
+
+    fn tries_soft_traced(
+        soft_result: SoftResult<(), SoftError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_soft_traced!(soft_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    fn tries_hard_traced(
+        hard_result: MalleableResult<(), SoftError, HardError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_hard_traced!(hard_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(()), false)]
+    #[case(SoftResult::SoftErr(SoftError), true)]
+    fn check_try_soft_traced(
+        #[case] soft_result: SoftResult<(), SoftError>,
+        #[case] expect_warn: bool,
+    ) {
+        let (result, events) = capture(|| tries_soft_traced(soft_result.clone()));
+        assert_eq!(result, Ok(soft_result));
+        assert_eq!(events.len(), expect_warn as usize);
+        if expect_warn {
+            assert_eq!(events[0].level, Level::WARN);
+            assert_eq!(events[0].field("soft_error"), Some("a soft error"));
+        }
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), false)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), true)]
+    #[case(Err(HardError), false)]
+    fn check_try_hard_traced(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] expect_warn: bool,
+    ) {
+        let (result, events) = capture(|| tries_hard_traced(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(events.len(), expect_warn as usize);
+        if expect_warn {
+            assert_eq!(events[0].level, Level::WARN);
+            assert_eq!(events[0].field("soft_error"), Some("a soft error"));
+        }
+    }
+
+    fn tries_hard_at(
+        hard_result: MalleableResult<(), SoftError, HardError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_hard_at!(hard_result, hard = Level::WARN, soft = Level::DEBUG);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), None)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Some((Level::DEBUG, "soft_error", "a soft error")))]
+    #[case(Err(HardError), Some((Level::WARN, "hard_error", "a real dangerous error")))]
+    fn check_try_hard_at(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] expected_event: Option<(Level, &str, &str)>,
+    ) {
+        let (result, events) = capture(|| tries_hard_at(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(events.len(), expected_event.is_some() as usize);
+        if let Some((level, field, value)) = expected_event {
+            assert_eq!(events[0].level, level);
+            assert_eq!(events[0].field(field), Some(value));
+        }
+    }
+
+    fn tries_hard_audit(
+        hard_result: MalleableResult<(), SoftError, HardError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_hard_audit!(hard_result, "save_user");
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), Level::INFO, "message", "success")]
+    #[case(
+        Ok(SoftResult::SoftErr(SoftError)),
+        Level::WARN,
+        "soft_error",
+        "a soft error"
+    )]
+    #[case(Err(HardError), Level::ERROR, "hard_error", "a real dangerous error")]
+    fn check_try_hard_audit(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] level: Level,
+        #[case] field: &str,
+        #[case] value: &str,
+    ) {
+        let (result, events) = capture(|| tries_hard_audit(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].level, level);
+        assert_eq!(events[0].field("audit_event"), Some("save_user"));
+        assert_eq!(events[0].field(field), Some(value));
+    }
+
+    fn tries_hard_log(
+        hard_result: MalleableResult<(), SoftError, HardError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_hard_log!(target: "db", hard_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), None)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Some((Level::WARN, "soft_error", "a soft error")))]
+    #[case(Err(HardError), Some((Level::ERROR, "hard_error", "a real dangerous error")))]
+    fn check_try_hard_log(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] expected_event: Option<(Level, &str, &str)>,
+    ) {
+        let (result, events) = capture(|| tries_hard_log(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(events.len(), expected_event.is_some() as usize);
+        if let Some((level, field, value)) = expected_event {
+            assert_eq!(events[0].target, "db");
+            assert_eq!(events[0].level, level);
+            assert_eq!(events[0].field(field), Some(value));
+        }
+    }
+
+    #[instrument(skip_all, fields(soft_error))]
+    fn records_soft(
+        soft_result: SoftResult<(), SoftError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        record_soft!(soft_result, "soft_error");
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(()), None)]
+    #[case(SoftResult::SoftErr(SoftError), Some("a soft error"))]
+    fn check_record_soft(
+        #[case] soft_result: SoftResult<(), SoftError>,
+        #[case] expected_field: Option<&str>,
+    ) {
+        let (result, spans) = capture_spans(|| records_soft(soft_result.clone()));
+        assert_eq!(result, Ok(soft_result));
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "records_soft");
+        assert_eq!(spans[0].field("soft_error"), expected_field);
+    }
+
+    fn tries_soft_with_span(
+        soft_result: SoftResult<(), SoftError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_soft_with_span!("lookup", soft_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(()))]
+    #[case(SoftResult::SoftErr(SoftError))]
+    fn check_try_soft_with_span(#[case] soft_result: SoftResult<(), SoftError>) {
+        let (result, spans) = capture_spans(|| tries_soft_with_span(soft_result.clone()));
+        assert_eq!(result, Ok(soft_result));
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "lookup");
+        assert_eq!(spans[0].entered, 1);
+        assert_eq!(spans[0].exited, 1);
+    }
+
+    #[instrument(skip_all, fields(soft_error, hard_error))]
+    fn tries_hard_record(
+        hard_result: MalleableResult<(), SoftError, HardError>,
+    ) -> MalleableResult<(), SoftError, HardError> {
+        try_hard_record!(hard_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), None, None)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Some("a soft error"), None)]
+    #[case(Err(HardError), None, Some("a real dangerous error"))]
+    fn check_try_hard_record(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] expected_soft: Option<&str>,
+        #[case] expected_hard: Option<&str>,
+    ) {
+        let (result, spans) = capture_spans(|| tries_hard_record(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "tries_hard_record");
+        assert_eq!(spans[0].field("soft_error"), expected_soft);
+        assert_eq!(spans[0].field("hard_error"), expected_hard);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct AppSoftError(SoftError);
+
+    impl From<SoftError> for AppSoftError {
+        fn from(e: SoftError) -> Self {
+            AppSoftError(e)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct AppHardError(HardError);
+
+    impl From<HardError> for AppHardError {
+        fn from(e: HardError) -> Self {
+            AppHardError(e)
+        }
+    }
+
+    fn check_soft_converts(
+        f: impl Fn(SoftResult<(), SoftError>) -> MalleableResult<(), AppSoftError, AppHardError>,
+    ) {
+        assert_eq!(f(SoftResult::Ok(())), Ok(SoftResult::Ok(())));
+        assert_eq!(
+            f(SoftResult::SoftErr(SoftError)),
+            Ok(SoftResult::SoftErr(AppSoftError(SoftError)))
+        );
+    }
+
+    fn check_hard_converts(
+        f: impl Fn(
+            MalleableResult<(), SoftError, HardError>,
+        ) -> MalleableResult<(), AppSoftError, AppHardError>,
+    ) {
+        assert_eq!(f(Ok(SoftResult::Ok(()))), Ok(SoftResult::Ok(())));
+        assert_eq!(
+            f(Ok(SoftResult::SoftErr(SoftError))),
+            Ok(SoftResult::SoftErr(AppSoftError(SoftError)))
+        );
+        assert_eq!(f(Err(HardError)), Err(AppHardError(HardError)));
+    }
+
+    #[test]
+    fn check_try_soft_traced_converts_errors() {
+        check_soft_converts(|soft_result| {
+            try_soft_traced!(soft_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_try_hard_traced_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_traced!(hard_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_try_hard_at_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_at!(hard_result, hard = Level::WARN, soft = Level::DEBUG);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_try_hard_log_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_log!(target: "db", hard_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_try_soft_with_span_converts_errors() {
+        check_soft_converts(|soft_result| {
+            try_soft_with_span!("lookup", soft_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_record_soft_converts_errors() {
+        check_soft_converts(|soft_result| {
+            record_soft!(soft_result, "soft_error");
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[test]
+    fn check_try_hard_record_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_record!(hard_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[cfg(feature = "log_json")]
+    #[derive(Debug, serde::Serialize, PartialEq, Eq, Clone)]
+    struct NotFound {
+        id: u32,
+    }
+
+    #[cfg(feature = "log_json")]
+    #[derive(Debug, serde::Serialize, PartialEq, Eq, Clone)]
+    enum DbError {
+        ConnectionLost,
+    }
+
+    #[cfg(feature = "log_json")]
+    fn tries_hard_json(
+        hard_result: MalleableResult<(), NotFound, DbError>,
+    ) -> MalleableResult<(), NotFound, DbError> {
+        try_hard_json!(hard_result);
+        Ok(SoftResult::Ok(()))
+    }
+
+    #[cfg(feature = "log_json")]
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), None)]
+    #[case(Ok(SoftResult::SoftErr(NotFound { id: 7 })), Some((Level::WARN, r#"{"id":7}"#)))]
+    #[case(Err(DbError::ConnectionLost), Some((Level::ERROR, r#""ConnectionLost""#)))]
+    fn check_try_hard_json(
+        #[case] hard_result: MalleableResult<(), NotFound, DbError>,
+        #[case] expected_event: Option<(Level, &str)>,
+    ) {
+        let (result, events) = capture(|| tries_hard_json(hard_result.clone()));
+        assert_eq!(result, hard_result);
+        assert_eq!(events.len(), expected_event.is_some() as usize);
+        if let Some((level, json)) = expected_event {
+            assert_eq!(events[0].level, level);
+            assert_eq!(events[0].field("error_json"), Some(json));
+        }
+    }
+
+    #[cfg(feature = "log_json")]
+    #[test]
+    fn check_try_hard_json_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_json!(hard_result);
+            Ok(SoftResult::Ok(()))
+        });
+    }
+
+    #[rstest]
+    #[case(SoftResult::Ok(()), Level::ERROR)]
+    #[case(SoftResult::SoftErr(SoftError), Level::ERROR)]
+    #[case(SoftResult::SoftErr(SoftError), Level::WARN)]
+    #[case(SoftResult::SoftErr(SoftError), Level::INFO)]
+    #[case(SoftResult::SoftErr(SoftError), Level::DEBUG)]
+    #[case(SoftResult::SoftErr(SoftError), Level::TRACE)]
+    fn check_inspect_soft_err_traced(
+        #[case] soft_result: SoftResult<(), SoftError>,
+        #[case] level: Level,
+    ) {
+        let (result, events) = capture(|| soft_result.clone().inspect_soft_err_traced(level));
+        assert_eq!(result, soft_result);
+        assert_eq!(events.len(), soft_result.is_soft_err() as usize);
+        if let Some(event) = events.first() {
+            assert_eq!(event.level, level);
+            assert_eq!(event.field("soft_error"), Some("a soft error"));
+            assert_eq!(event.field("message"), Some("soft error"));
+        }
+    }
+
+    #[rstest]
+    #[case(Ok(SoftResult::Ok(())), None)]
+    #[case(Ok(SoftResult::SoftErr(SoftError)), Some((Level::WARN, "soft_error", "a soft error")))]
+    #[case(Err(HardError), Some((Level::ERROR, "hard_error", "a real dangerous error")))]
+    fn check_observe_malleable(
+        #[case] hard_result: MalleableResult<(), SoftError, HardError>,
+        #[case] expected_event: Option<(Level, &str, &str)>,
+    ) {
+        let ((), events) = capture(|| observe_malleable(&hard_result));
+        assert_eq!(events.len(), expected_event.is_some() as usize);
+        if let Some((level, field, message)) = expected_event {
+            assert_eq!(events[0].level, level);
+            assert_eq!(events[0].field(field), Some(message));
+        }
+    }
+
+    #[test]
+    fn check_try_hard_audit_converts_errors() {
+        check_hard_converts(|hard_result| {
+            try_hard_audit!(hard_result, "save_user");
+            Ok(SoftResult::Ok(()))
+        });
+    }
+}
@@ -0,0 +1,9 @@
+//! Compile tests for the `#[malleable]` attribute.
+#![cfg(feature = "macros")]
+
+#[test]
+fn malleable() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/malleable/pass_*.rs");
+    t.compile_fail("tests/ui/malleable/fail_*.rs");
+}
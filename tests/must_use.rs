@@ -0,0 +1,8 @@
+//! Compile tests for the `#[must_use]` message of `SoftResult`.
+
+#[test]
+fn must_use() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/must_use/pass_*.rs");
+    t.compile_fail("tests/ui/must_use/fail_*.rs");
+}
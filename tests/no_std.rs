@@ -0,0 +1,50 @@
+//! Checks that the `tests/no_std` crate, which uses `try_hard` without its default features, builds under `no_std`.
+//!
+//! The crate is checked for a bare-metal target, which has no `std` in its sysroot, so any `std` path fails the build.
+//! The test is skipped if none of the targets is installed, add one with `rustup target add thumbv7em-none-eabi`.
+
+use std::path::Path;
+use std::process::Command;
+
+const TARGETS: [&str; 2] = ["thumbv7em-none-eabi", "x86_64-unknown-none"];
+
+fn installed_target() -> Option<&'static str> {
+    TARGETS.into_iter().find(|target| {
+        Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+            .args(["--print", "target-libdir", "--target", target])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .is_some_and(|output| {
+                Path::new(String::from_utf8_lossy(&output.stdout).trim()).exists()
+            })
+    })
+}
+
+#[test]
+fn builds_without_std() {
+    let Some(target) = installed_target() else {
+        eprintln!("skipping the no_std build, none of {TARGETS:?} is installed");
+        return;
+    };
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/no_std/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_std");
+    for features in ["", "try_hard/alloc"] {
+        let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+            .arg("check")
+            .arg("--manifest-path")
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .arg("--target")
+            .arg(target)
+            .arg("--features")
+            .arg(features)
+            .status()
+            .expect("failed to run cargo");
+        assert!(
+            status.success(),
+            "no_std build failed for {target} with features {features:?}"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Uses `try_hard` from a `no_std` crate, checked by the `no_std` integration test.
+#![no_std]
+
+use try_hard::{MalleableResult, SoftResult};
+
+pub struct SoftError;
+
+#[derive(Debug)]
+pub struct HardError;
+
+pub fn validate(input: u32) -> SoftResult<u32, SoftError> {
+    if input == 0 {
+        return SoftResult::SoftErr(SoftError);
+    }
+    SoftResult::Ok(input)
+}
+
+pub fn process(input: u32) -> MalleableResult<u32, SoftError, HardError> {
+    let valid = try_hard::try_soft!(validate(input));
+    if valid > 100 {
+        try_hard::hard_bail!(HardError);
+    }
+    Ok(SoftResult::Ok(valid))
+}
+
+pub fn double(input: u32) -> u32 {
+    validate(input).map(|x| x * 2).unwrap_or_default()
+}
+
+pub fn must_be_soft(input: u32) -> SoftError {
+    validate(input).unwrap_soft_err()
+}
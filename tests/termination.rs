@@ -0,0 +1,39 @@
+//! Checks the exit codes and outputs of a `main` returning a `MalleableResult`.
+//! This test has no harness: it re-runs its own binary, selecting the outcome of `main` with an environment variable.
+
+use std::process::Command;
+
+use try_hard::{MalleableResult, SoftResult};
+
+const OUTCOME: &str = "TRY_HARD_TERMINATION_OUTCOME";
+
+#[derive(Debug)]
+struct SoftError;
+
+#[derive(Debug)]
+struct HardError;
+
+fn check(outcome: &str, success: bool, stdout: &str, stderr: &str) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .env(OUTCOME, outcome)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.success(), success, "{outcome}: {output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), stdout, "{outcome}");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), stderr, "{outcome}");
+}
+
+fn main() -> MalleableResult<(), SoftError, HardError> {
+    match std::env::var(OUTCOME).as_deref() {
+        Ok("ok") => Ok(SoftResult::Ok(())),
+        Ok("soft") => Ok(SoftResult::SoftErr(SoftError)),
+        Ok("hard") => Err(HardError),
+        _ => {
+            check("ok", true, "", "");
+            check("soft", true, "SoftError\n", "");
+            check("hard", false, "", "Error: HardError\n");
+            println!("termination: ok");
+            Ok(SoftResult::Ok(()))
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use try_hard::*;
+
+#[malleable(soft)]
+fn validate(input: i32) -> MalleableResult<i32, (), ()> {
+    input
+}
+
+fn main() {}
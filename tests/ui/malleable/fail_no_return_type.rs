@@ -0,0 +1,6 @@
+use try_hard::*;
+
+#[malleable]
+fn no_return_type() {}
+
+fn main() {}
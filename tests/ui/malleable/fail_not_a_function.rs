@@ -0,0 +1,6 @@
+use try_hard::*;
+
+#[malleable]
+struct NotAFunction;
+
+fn main() {}
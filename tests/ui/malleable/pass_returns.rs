@@ -0,0 +1,46 @@
+use try_hard::*;
+
+#[derive(Debug, PartialEq)]
+struct SoftError;
+
+#[derive(Debug, PartialEq)]
+struct HardError;
+
+#[malleable]
+fn validate(input: i32) -> MalleableResult<i32, SoftError, HardError> {
+    if input < 0 {
+        soft_bail!(SoftError);
+    }
+    if input == 0 {
+        hard_bail!(HardError);
+    }
+    if input > 100 {
+        return 100;
+    }
+    input
+}
+
+#[malleable]
+fn double(input: i32) -> MalleableResult<i32, SoftError, HardError> {
+    let valid = try_hard!(validate(input));
+    let closure = |x: i32| -> Option<i32> {
+        if x == 0 {
+            return None;
+        }
+        Some(x)
+    };
+    closure(valid).unwrap_or(0) * 2
+}
+
+#[malleable]
+fn unit() -> MalleableResult<(), SoftError, HardError> {
+    return;
+}
+
+fn main() {
+    assert_eq!(double(2), Ok(SoftResult::Ok(4)));
+    assert_eq!(double(200), Ok(SoftResult::Ok(200)));
+    assert_eq!(double(-1), Ok(SoftResult::SoftErr(SoftError)));
+    assert_eq!(double(0), Err(HardError));
+    assert_eq!(unit(), Ok(SoftResult::Ok(())));
+}
@@ -0,0 +1,43 @@
+#![deny(warnings)]
+
+use try_hard as renamed;
+use try_hard::*;
+
+#[derive(Debug, PartialEq)]
+struct SoftError;
+
+#[derive(Debug, PartialEq)]
+struct HardError;
+
+#[malleable]
+fn diverging_tail(input: i32) -> MalleableResult<i32, SoftError, HardError> {
+    if input > 0 {
+        return input;
+    }
+    soft_bail!(SoftError)
+}
+
+#[malleable]
+fn explicit_results(input: i32) -> MalleableResult<i32, SoftError, HardError> {
+    if input < 0 {
+        return Ok(SoftResult::SoftErr(SoftError));
+    }
+    if input == 0 {
+        return Result::Err(HardError);
+    }
+    Err(HardError)
+}
+
+#[malleable(crate = renamed)]
+fn renamed_crate(input: i32) -> MalleableResult<i32, SoftError, HardError> {
+    input
+}
+
+fn main() {
+    assert_eq!(diverging_tail(1), Ok(SoftResult::Ok(1)));
+    assert_eq!(diverging_tail(0), Ok(SoftResult::SoftErr(SoftError)));
+    assert_eq!(explicit_results(-1), Ok(SoftResult::SoftErr(SoftError)));
+    assert_eq!(explicit_results(0), Err(HardError));
+    assert_eq!(explicit_results(1), Err(HardError));
+    assert_eq!(renamed_crate(1), Ok(SoftResult::Ok(1)));
+}
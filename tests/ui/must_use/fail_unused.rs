@@ -0,0 +1,11 @@
+#![deny(unused_must_use)]
+
+use try_hard::SoftResult;
+
+fn lookup() -> SoftResult<i32, String> {
+    SoftResult::SoftErr("not found".to_string())
+}
+
+fn main() {
+    lookup();
+}
@@ -0,0 +1,144 @@
+//! Procedural macros for the `try_hard` crate.
+//! Use them through `try_hard`, with its `macros` feature enabled.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
+use syn::{Expr, ExprAsync, ExprClosure, Item, ItemFn, Path, ReturnType, Stmt};
+
+/// Wraps the returned values of a function into `Ok(SoftResult::Ok(_))`.
+///
+/// Both `return x;` and the trailing expression of the function body are wrapped,
+/// so a function returning a `MalleableResult` can return its ok values directly.
+/// Returns inside closures, async blocks, nested items and macros are left untouched,
+/// so [try_hard], [try_soft] and the bail macros still short-circuit as usual.
+///
+/// Some values are not wrapped, since they are not ok values:
+/// - A value already spelled as `Ok(_)`, `Err(_)`, `Result::Ok(_)` or `Result::Err(_)` is returned as is.
+/// - A trailing macro invocation, such as `soft_bail!(e)`, is left as is, since it may diverge.
+///   Bind the value of other macros, such as `vec!`, to a variable, or return it with `return`.
+///
+/// The generated code refers to `::try_hard`. If the dependency is renamed, pass its path with
+/// `#[malleable(crate = path)]`.
+///
+/// [try_hard]: https://docs.rs/try_hard/latest/try_hard/macro.try_hard.html
+/// [try_soft]: https://docs.rs/try_hard/latest/try_hard/macro.try_soft.html
+#[proc_macro_attribute]
+pub fn malleable(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut krate: Path = syn::parse_quote!(::try_hard);
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            krate = meta.value()?.parse()?;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported `#[malleable]` argument, expected `crate = path`"))
+        }
+    });
+    if let Err(e) = syn::parse::Parser::parse(parser, attr) {
+        return e.to_compile_error().into();
+    }
+    let mut function = match syn::parse::<Item>(item) {
+        Ok(Item::Fn(function)) => function,
+        Ok(item) => {
+            return syn::Error::new(
+                first_token_span(&item),
+                "`#[malleable]` can only be applied to functions",
+            )
+            .to_compile_error()
+            .into();
+        }
+        Err(e) => return e.to_compile_error().into(),
+    };
+    if let ReturnType::Default = function.sig.output {
+        return syn::Error::new(
+            function.sig.fn_token.span,
+            "`#[malleable]` functions must declare a `MalleableResult` return type",
+        )
+        .to_compile_error()
+        .into();
+    }
+    wrap_returns(&mut function, &krate);
+    quote!(#function).into()
+}
+
+/// Points errors at the first token, so they span the same code on every compiler.
+fn first_token_span(tokens: &impl ToTokens) -> Span {
+    tokens
+        .to_token_stream()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |token| token.span())
+}
+
+/// Whether the expression is already spelled as a `Result`, like `Ok(_)` or `Result::Err(_)`.
+fn is_result(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    let Expr::Path(path) = call.func.as_ref() else {
+        return false;
+    };
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    match segments.as_slice() {
+        [.., variant] if variant != "Ok" && variant != "Err" => false,
+        [_] => true,
+        [.., parent, _] => parent == "Result",
+        [] => false,
+    }
+}
+
+fn wrap(expr: &Expr, krate: &Path) -> Expr {
+    syn::parse2(quote_spanned! {expr.span()=>
+        ::core::result::Result::Ok(#krate::SoftResult::Ok(#expr))
+    })
+    .expect("a wrapped expression is a valid expression")
+}
+
+fn wrap_returns(function: &mut ItemFn, krate: &Path) {
+    WrapReturns { krate }.visit_block_mut(&mut function.block);
+    let Some(Stmt::Expr(tail, None)) = function.block.stmts.last_mut() else {
+        return;
+    };
+    if !matches!(
+        tail,
+        Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) | Expr::Macro(_)
+    ) && !is_result(tail)
+    {
+        *tail = wrap(tail, krate);
+    }
+}
+
+/// Wraps the values of the `return` expressions that belong to the visited function.
+struct WrapReturns<'a> {
+    krate: &'a Path,
+}
+
+impl VisitMut for WrapReturns<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        syn::visit_mut::visit_expr_mut(self, expr);
+        if let Expr::Return(ret) = expr {
+            let value = match ret.expr.take() {
+                Some(value) => *value,
+                None => syn::parse_quote!(()),
+            };
+            ret.expr = Some(Box::new(if is_result(&value) {
+                value
+            } else {
+                wrap(&value, self.krate)
+            }));
+        }
+    }
+
+    fn visit_expr_closure_mut(&mut self, _closure: &mut ExprClosure) {}
+
+    fn visit_expr_async_mut(&mut self, _async_block: &mut ExprAsync) {}
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {}
+}